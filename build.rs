@@ -0,0 +1,72 @@
+// generates one test per example and part found in examples/<year>/day_NN,
+// see `examples::check`
+use std::{env, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed=examples");
+
+    let mut tests = String::new();
+
+    for (year, day, dir) in example_dirs(Path::new("examples")) {
+        let mut names = fs::read_dir(&dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter_map(|e| {
+                let path = e.path();
+                (path.extension()? == "txt")
+                    .then(|| path.file_stem()?.to_str().map(String::from))?
+            })
+            .collect::<Vec<_>>();
+        names.sort();
+
+        for name in names {
+            for part in ['a', 'b'] {
+                if !dir.join(format!("{name}.{part}")).exists() {
+                    continue;
+                }
+
+                let ident = name
+                    .chars()
+                    .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                    .collect::<String>();
+
+                tests += &format!(
+                    "#[test]\nfn y{year}_day_{day:02}_{ident}_{part}() {{\n    \
+                     super::check({year}, {day}, {name:?}, '{part}');\n}}\n\n"
+                );
+            }
+        }
+    }
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("example_tests.rs");
+    fs::write(out, tests).unwrap();
+}
+
+fn example_dirs(root: &Path) -> Vec<(u16, u8, std::path::PathBuf)> {
+    let mut dirs = Vec::new();
+
+    for year in fs::read_dir(root).into_iter().flatten().flatten() {
+        let Some(year_num) = year
+            .file_name()
+            .to_str()
+            .and_then(|y| y.parse::<u16>().ok())
+        else {
+            continue;
+        };
+
+        for day in fs::read_dir(year.path()).into_iter().flatten().flatten() {
+            let day_num = day
+                .file_name()
+                .to_str()
+                .and_then(|d| d.strip_prefix("day_")?.parse::<u8>().ok());
+
+            if let Some(day_num) = day_num {
+                dirs.push((year_num, day_num, day.path()));
+            }
+        }
+    }
+
+    dirs.sort();
+    dirs
+}
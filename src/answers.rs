@@ -0,0 +1,160 @@
+use std::{collections::BTreeMap, fs, io, path::PathBuf};
+
+use crate::config;
+
+/// confirmed answers, stored as `<day> <part> <answer>` lines
+pub struct AnswerStore {
+    year: u16,
+    answers: BTreeMap<(u8, char), String>,
+}
+
+impl AnswerStore {
+    fn path(year: u16) -> PathBuf {
+        config::get().answers_dir().join(format!("{year}.txt"))
+    }
+
+    pub fn load(year: u16) -> Result<Self, Box<dyn std::error::Error>> {
+        let path = Self::path(year);
+
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                return Ok(Self {
+                    year,
+                    answers: BTreeMap::new(),
+                })
+            }
+            Err(e) => return Err(format!("could not read {}: {e}", path.display()).into()),
+        };
+
+        let answers = parse(&content).map_err(|e| format!("{}:{e}", path.display()))?;
+
+        Ok(Self { year, answers })
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let path = Self::path(self.year);
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(path, self.render())
+    }
+
+    fn render(&self) -> String {
+        self.answers
+            .iter()
+            .map(|((day, part), answer)| format!("{day} {part} {answer}\n"))
+            .collect()
+    }
+
+    pub fn set(&mut self, day: u8, part: char, answer: String) {
+        self.answers.insert((day, part), answer);
+    }
+
+    pub fn get(&self, day: u8, part: char) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (u8, char, &str)> {
+        self.answers
+            .iter()
+            .map(|((day, part), answer)| (*day, *part, answer.as_str()))
+    }
+}
+
+/// errors start with the line number
+fn parse(content: &str) -> Result<BTreeMap<(u8, char), String>, String> {
+    let mut answers = BTreeMap::new();
+
+    for (i, line) in content.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let mut fields = line.splitn(3, ' ');
+        let day = fields.next().and_then(|d| d.parse::<u8>().ok());
+        let part = match fields.next() {
+            Some("a") => Some('a'),
+            Some("b") => Some('b'),
+            _ => None,
+        };
+
+        let (Some(day), Some(part), Some(answer)) = (day, part, fields.next()) else {
+            return Err(format!("{}: malformed answer line", i + 1));
+        };
+
+        answers.insert((day, part), answer.to_string());
+    }
+
+    Ok(answers)
+}
+
+/// saves `answer` as the confirmed answer for this day and part
+pub fn record(
+    year: u16,
+    day: u8,
+    part: char,
+    answer: String,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut store = AnswerStore::load(year)?;
+    store.set(day, part, answer);
+    store.save()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use super::{parse, AnswerStore};
+
+    use indoc::indoc;
+
+    #[test]
+    fn parsed() {
+        let answers = parse(indoc! {"
+            1 a 142
+
+            1 b 281
+            8 a EFJ KLZ
+        "})
+        .unwrap();
+
+        assert_eq!(
+            answers,
+            BTreeMap::from([
+                ((1, 'a'), "142".to_string()),
+                ((1, 'b'), "281".to_string()),
+                ((8, 'a'), "EFJ KLZ".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut store = AnswerStore {
+            year: 2023,
+            answers: BTreeMap::new(),
+        };
+        store.set(2, 'b', "2286".into());
+        store.set(1, 'a', "142".into());
+
+        assert_eq!(store.render(), "1 a 142\n2 b 2286\n");
+        assert_eq!(parse(&store.render()).unwrap(), store.answers);
+    }
+
+    #[test]
+    fn rejected() {
+        assert_eq!(
+            parse("1 a 142\n1 c 281\n"),
+            Err("2: malformed answer line".into())
+        );
+        assert!(parse("1 A 142").is_err());
+        assert!(parse("1 ab 142").is_err());
+        assert!(parse("one a 142").is_err());
+        assert!(parse("1 a").is_err());
+    }
+}
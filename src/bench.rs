@@ -0,0 +1,393 @@
+use std::{
+    fs, hint,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    common::DynSolution,
+    input::{self, CacheMode},
+    memory::Usage,
+    runner::{self, format_time, Outcome, RunOptions},
+    solutions,
+};
+
+pub struct BenchOptions {
+    pub warmup: usize,
+    pub iterations: usize,
+    pub json: bool,
+    pub save_baseline: Option<PathBuf>,
+    pub baseline: Option<PathBuf>,
+    pub input: Option<PathBuf>,
+    /// relative median change, in percent, above which a part is flagged
+    pub threshold: f64,
+    /// only applies to the first run, a part that fits is benchmarked as is
+    pub limit: Option<Duration>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct BenchResult {
+    pub day: u8,
+    pub name: String,
+    /// `'p'` for the shared parse step
+    pub part: char,
+    #[serde(flatten)]
+    pub stats: Stats,
+    /// what the first run allocated, if built with the counting allocator
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory: Option<Usage>,
+}
+
+/// timing statistics, in nanoseconds
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub p95: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut nanos = samples
+            .iter()
+            .map(|d| d.as_nanos() as f64)
+            .collect::<Vec<_>>();
+        nanos.sort_by(f64::total_cmp);
+
+        let n = nanos.len();
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let variance = nanos.iter().map(|t| (t - mean).powi(2)).sum::<f64>() / n as f64;
+
+        let median = if n % 2 == 0 {
+            (nanos[n / 2 - 1] + nanos[n / 2]) / 2.0
+        } else {
+            nanos[n / 2]
+        };
+
+        // nearest-rank percentile
+        let p95 = nanos[((n as f64 * 0.95).ceil() as usize).max(1) - 1];
+
+        Self {
+            iterations: n,
+            min: nanos[0],
+            median,
+            mean,
+            p95,
+            stddev: variance.sqrt(),
+        }
+    }
+}
+
+/// the outcome of the first run if it did not produce an answer. the input is
+/// parsed once up front, so samples time the part alone, see `bench_parse`.
+/// memory is only counted on the first run, it doesn't change between runs
+pub fn bench_part(
+    solution: &'static dyn DynSolution,
+    day: u8,
+    part: char,
+    input: &str,
+    options: &BenchOptions,
+) -> Result<(Stats, Option<Usage>), Outcome> {
+    let run_options = RunOptions {
+        limit: options.limit,
+        progress: false,
+    };
+
+    let first = runner::run_day(solution, day, &[part], input, run_options)
+        .parts
+        .remove(0);
+    if !matches!(first.outcome, Outcome::Solved(_)) {
+        return Err(first.outcome);
+    }
+
+    let parsed = solution
+        .parse(input)
+        .map_err(|e| Outcome::Failed(e.parsing(day)))?;
+
+    let stats = sample(options, || {
+        let _ = hint::black_box(parsed.solve(part));
+    });
+
+    Ok((stats, first.memory))
+}
+
+/// times the parse step on its own, only call it once a part ran, which
+/// shows that parsing works and fits the time limit
+pub fn bench_parse(solution: &dyn DynSolution, input: &str, options: &BenchOptions) -> Stats {
+    sample(options, || {
+        let _ = hint::black_box(solution.parse(hint::black_box(input)));
+    })
+}
+
+/// the warmup runs, minus the one already done, then the measured ones
+fn sample(options: &BenchOptions, mut run: impl FnMut()) -> Stats {
+    for _ in 1..options.warmup {
+        run();
+    }
+
+    let samples = (0..options.iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            run();
+            start.elapsed()
+        })
+        .collect::<Vec<_>>();
+
+    Stats::from_samples(&samples)
+}
+
+/// benches the given days (all registered days if `None`) and parts,
+/// returns whether none of them failed
+pub fn bench(
+    year: u16,
+    day: Option<u8>,
+    parts: &[char],
+    options: &BenchOptions,
+    mode: CacheMode,
+) -> bool {
+    let days = match day {
+        Some(day) => vec![day],
+        None if options.input.is_some() => {
+            eprintln!("[-] A custom input needs a single day");
+            return false;
+        }
+        None => match solutions::year(year) {
+            Ok(solutions) => solutions.iter().map(|s| s.day()).collect(),
+            Err(e) => {
+                eprintln!("[-] {e}");
+                return false;
+            }
+        },
+    };
+
+    let mut results = Vec::new();
+    let mut ok = true;
+
+    for day in days {
+        let solution = match solutions::get(year, day) {
+            Ok(solution) => solution,
+            Err(e) => {
+                eprintln!("[-] {e}");
+                ok = false;
+                continue;
+            }
+        };
+
+        let input = match &options.input {
+            Some(path) => input::read(path),
+            None => input::load(year, day, mode),
+        };
+
+        let input = match input {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("[-] {e}");
+                ok = false;
+                continue;
+            }
+        };
+
+        let mut day_results = Vec::new();
+
+        for &part in parts {
+            let (stats, memory) = match bench_part(solution, day, part, &input, options) {
+                Ok(measured) => measured,
+                Err(outcome) => {
+                    let label = format!("Day {day} ({})", part.to_uppercase());
+                    eprintln!("[-] {label}: not benchmarked, {}", outcome.cell());
+                    ok &= !outcome.is_error();
+                    continue;
+                }
+            };
+
+            let result = BenchResult {
+                day,
+                name: solution.name().into(),
+                part,
+                stats,
+                memory,
+            };
+
+            day_results.push(result);
+        }
+
+        // the parts were timed without it, so parsing gets its own row
+        if !day_results.is_empty() {
+            day_results.insert(
+                0,
+                BenchResult {
+                    day,
+                    name: solution.name().into(),
+                    part: 'p',
+                    stats: bench_parse(solution, &input, options),
+                    memory: None,
+                },
+            );
+        }
+
+        if !options.json {
+            day_results.iter().for_each(print_result);
+        }
+
+        results.extend(day_results);
+    }
+
+    if options.json {
+        println!("{}", serde_json::to_string_pretty(&results).unwrap());
+    }
+
+    // stdout only holds the JSON document when one was asked for
+    let say = |line: String| {
+        if options.json {
+            eprintln!("{line}");
+        } else {
+            println!("{line}");
+        }
+    };
+
+    if let Some(path) = &options.baseline {
+        match load_baseline(path) {
+            Ok(baseline) => compare(&results, &baseline, options.threshold)
+                .into_iter()
+                .for_each(say),
+            Err(e) => eprintln!("[-] Could not load baseline {}: {e}", path.display()),
+        }
+    }
+
+    if let Some(path) = &options.save_baseline {
+        match save_baseline(path, &results) {
+            Ok(()) => say(format!("[*] Saved baseline to {}", path.display())),
+            Err(e) => eprintln!("[-] Could not save baseline {}: {e}", path.display()),
+        }
+    }
+
+    ok
+}
+
+fn save_baseline(path: &Path, results: &[BenchResult]) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    fs::write(path, serde_json::to_string_pretty(results)?)?;
+
+    Ok(())
+}
+
+fn load_baseline(path: &Path) -> Result<Vec<BenchResult>, Box<dyn std::error::Error>> {
+    Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+}
+
+#[derive(Debug, PartialEq)]
+enum Change {
+    Slower,
+    Faster,
+    Unchanged,
+}
+
+impl Change {
+    fn classify(old: f64, new: f64, threshold: f64) -> Self {
+        let percent = (new - old) / old * 100.0;
+
+        if percent > threshold {
+            Self::Slower
+        } else if percent < -threshold {
+            Self::Faster
+        } else {
+            Self::Unchanged
+        }
+    }
+}
+
+/// one line per result, after a header
+fn compare(results: &[BenchResult], baseline: &[BenchResult], threshold: f64) -> Vec<String> {
+    let mut lines = vec![format!(
+        "[*] Comparing medians against baseline (threshold {threshold}%)"
+    )];
+
+    for result in results {
+        let label = format!("Day {} ({})", result.day, result.part.to_uppercase());
+
+        let Some(old) = baseline
+            .iter()
+            .find(|b| b.day == result.day && b.part == result.part)
+        else {
+            lines.push(format!("[?] {label}: not in baseline"));
+            continue;
+        };
+
+        let (old, new) = (old.stats.median, result.stats.median);
+        let percent = (new - old) / old * 100.0;
+
+        let (marker, change) = match Change::classify(old, new, threshold) {
+            Change::Slower => ('-', "slower"),
+            Change::Faster => ('+', "faster"),
+            Change::Unchanged => ('=', "unchanged"),
+        };
+
+        lines.push(format!(
+            "[{marker}] {label}: {} -> {} ({percent:+.1}%, {change})",
+            format_time(old),
+            format_time(new),
+        ));
+    }
+
+    lines
+}
+
+fn print_result(result: &BenchResult) {
+    let stats = &result.stats;
+    let memory = match result.memory {
+        Some(usage) => format!(" | {usage}"),
+        None => String::new(),
+    };
+
+    println!(
+        "[*] {} ({}-{}): min {} | median {} | mean {} | p95 {} | stddev {}{memory} ({} iterations)",
+        result.name,
+        result.day,
+        result.part.to_uppercase(),
+        format_time(stats.min),
+        format_time(stats.median),
+        format_time(stats.mean),
+        format_time(stats.p95),
+        format_time(stats.stddev),
+        stats.iterations,
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{Change, Stats};
+
+    #[test]
+    fn stats() {
+        let samples = [5, 1, 3, 2, 4, 6, 7, 8, 9, 10]
+            .map(Duration::from_nanos)
+            .to_vec();
+
+        let stats = Stats::from_samples(&samples);
+
+        assert_eq!(stats.iterations, 10);
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.median, 5.5);
+        assert_eq!(stats.mean, 5.5);
+        assert_eq!(stats.p95, 10.0);
+        assert!((stats.stddev - 8.25f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn change() {
+        assert_eq!(Change::classify(100.0, 125.0, 10.0), Change::Slower);
+        assert_eq!(Change::classify(100.0, 105.0, 10.0), Change::Unchanged);
+        assert_eq!(Change::classify(100.0, 95.0, 10.0), Change::Unchanged);
+        assert_eq!(Change::classify(100.0, 50.0, 10.0), Change::Faster);
+    }
+}
@@ -0,0 +1,225 @@
+use std::{
+    io::{self, IsTerminal},
+    num::NonZeroUsize,
+    path::PathBuf,
+    str::FromStr,
+    thread,
+};
+
+use clap::{Parser, Subcommand};
+
+use crate::{
+    config::Config,
+    input::CacheMode,
+    report::Format,
+    runner::{self, RunOptions},
+};
+
+#[derive(Parser)]
+#[command(about = "Runs, benchmarks and submits Advent of Code solutions")]
+pub struct Cli {
+    /// Event year [default: 2023]
+    #[arg(long, global = true)]
+    pub year: Option<u16>,
+
+    /// Fetch inputs again, overwriting the cache
+    #[arg(long, global = true)]
+    pub refresh: bool,
+
+    /// Fetch inputs without reading or writing the cache
+    #[arg(long, global = true, conflicts_with = "refresh")]
+    pub no_cache: bool,
+
+    /// Seconds a part may run before it is given up on, 0 for no limit [default: 0]
+    #[arg(long, global = true, value_parser = parse_timeout)]
+    pub timeout: Option<f64>,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+impl Cli {
+    pub fn cache_mode(&self) -> CacheMode {
+        if self.no_cache {
+            CacheMode::Bypass
+        } else if self.refresh {
+            CacheMode::Refresh
+        } else {
+            CacheMode::Use
+        }
+    }
+
+    /// the flag, then the config file, then the default
+    pub fn year(&self, config: &Config) -> u16 {
+        self.year.or(config.year).unwrap_or(2023)
+    }
+
+    /// errors on a timeout that is not a valid limit
+    pub fn run_options(&self, config: &Config) -> Result<RunOptions, String> {
+        // no limit unless asked for, some days legitimately take minutes
+        let timeout = self.timeout.or(config.timeout).unwrap_or(0.0);
+
+        Ok(RunOptions {
+            limit: runner::time_limit(timeout)?,
+            progress: io::stderr().is_terminal(),
+        })
+    }
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Run a day, both parts unless one is given
+    Run {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(value_parser = parse_part)]
+        part: Option<char>,
+        /// Read the input from this file, or stdin if `-`, instead of the cache
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// How to print the results [default: text]
+        #[arg(long, value_enum)]
+        format: Option<Format>,
+    },
+    /// Run every registered day and print a summary table
+    All {
+        /// Days run at once, defaults to the number of cores
+        #[arg(long, short)]
+        jobs: Option<usize>,
+        /// How to print the results [default: text]
+        #[arg(long, value_enum)]
+        format: Option<Format>,
+    },
+    /// Benchmark a day, or every registered day
+    Bench {
+        #[arg(default_value = "all")]
+        days: Days,
+        #[arg(value_parser = parse_part)]
+        part: Option<char>,
+        /// Runs before measuring
+        #[arg(long, default_value_t = 3)]
+        warmup: usize,
+        /// Measured runs
+        #[arg(long, default_value_t = 10)]
+        iterations: usize,
+        /// Print the results as JSON
+        #[arg(long)]
+        json: bool,
+        /// Save the results as a baseline
+        #[arg(long)]
+        save_baseline: Option<PathBuf>,
+        /// Compare the results against a saved baseline
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Read the input from this file, or stdin if `-`, instead of the cache
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Median change, in percent, flagged when comparing against a baseline
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Download a day's input into the cache
+    Fetch {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Save the examples and expected answers from a day's puzzle page
+    FetchExample {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Overwrite example files that already exist
+        #[arg(long)]
+        force: bool,
+    },
+    /// Run a part and submit its answer
+    Submit {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(value_parser = parse_part)]
+        part: char,
+    },
+    /// Record a confirmed answer, running the part if none is given
+    Record {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(value_parser = parse_part)]
+        part: char,
+        answer: Option<String>,
+    },
+    /// Check every recorded answer against its solution
+    Verify {
+        /// Days run at once, defaults to the number of cores
+        #[arg(long, short)]
+        jobs: Option<usize>,
+    },
+    /// Run every registered day and rewrite the progress table in the README
+    Readme {
+        #[arg(long, default_value = "README.md")]
+        path: PathBuf,
+        /// Days run at once, defaults to the number of cores
+        #[arg(long, short)]
+        jobs: Option<usize>,
+    },
+    /// Create and register a solution module for a new day
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Puzzle title, fetched from the puzzle page if not given
+        #[arg(long)]
+        name: Option<String>,
+    },
+}
+
+#[derive(Clone, Copy)]
+pub enum Days {
+    All,
+    Day(u8),
+}
+
+impl FromStr for Days {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(Self::All),
+            _ => match s.parse() {
+                Ok(day @ 1..=25) => Ok(Self::Day(day)),
+                _ => Err(format!("`{s}` is neither a day from 1 to 25 nor `all`")),
+            },
+        }
+    }
+}
+
+fn parse_timeout(s: &str) -> Result<f64, String> {
+    let seconds = s
+        .parse()
+        .map_err(|_| format!("`{s}` is not a number of seconds"))?;
+
+    runner::time_limit(seconds).map(|_| seconds)
+}
+
+fn parse_part(s: &str) -> Result<char, String> {
+    match s.to_ascii_lowercase().as_str() {
+        "a" | "1" => Ok('a'),
+        "b" | "2" => Ok('b'),
+        _ => Err(format!("`{s}` is not a part, expected a or b")),
+    }
+}
+
+/// the flag, then the config file, then plain text
+pub fn format(format: Option<Format>, config: &Config) -> Format {
+    format.or(config.format).unwrap_or_default()
+}
+
+/// the number of cores if not given
+pub fn jobs(jobs: Option<usize>) -> usize {
+    jobs.unwrap_or_else(|| thread::available_parallelism().map_or(1, NonZeroUsize::get))
+}
+
+/// both parts if none was given
+pub fn parts(part: Option<char>) -> Vec<char> {
+    match part {
+        Some(part) => vec![part],
+        None => vec!['a', 'b'],
+    }
+}
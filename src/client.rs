@@ -0,0 +1,60 @@
+use crate::{config, input};
+
+pub struct Client {
+    base_url: String,
+    token: String,
+}
+
+impl Client {
+    pub fn new(base_url: impl Into<String>, token: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            token: token.into(),
+        }
+    }
+
+    /// `None` if no session token is configured
+    pub fn from_config() -> Option<Self> {
+        let config = config::get();
+        let token = config.token.as_ref()?;
+
+        Some(Self::new(config.base_url(), token))
+    }
+
+    fn url(&self, year: u16, path: &str) -> String {
+        format!("{}/{year}/{path}", self.base_url)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.token)
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String, Box<dyn std::error::Error>> {
+        let input = ureq::get(&self.url(year, &format!("day/{day}/input")))
+            .set("Cookie", &self.cookie())
+            .call()?
+            .into_string()?;
+
+        Ok(input::normalize(&input))
+    }
+
+    pub fn puzzle(&self, year: u16, day: u8) -> Result<String, Box<dyn std::error::Error>> {
+        Ok(ureq::get(&self.url(year, &format!("day/{day}")))
+            .set("Cookie", &self.cookie())
+            .call()?
+            .into_string()?)
+    }
+
+    pub fn answer(
+        &self,
+        year: u16,
+        day: u8,
+        level: u8,
+        answer: &str,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        Ok(ureq::post(&self.url(year, &format!("day/{day}/answer")))
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &level.to_string()), ("answer", answer)])?
+            .into_string()?)
+    }
+}
@@ -1,20 +1,79 @@
 use std::fmt::{self, Display};
 
-#[derive(Debug, PartialEq)]
+use num_bigint::{BigInt, BigUint};
+
+use super::{ocr, SolveError};
+
+#[derive(Debug, Clone)]
 pub enum Answer {
     String(String),
     Number(u64),
+    Signed(i64),
+    /// anything that doesn't fit in 64 bits
+    Big(BigInt),
     Float(f64),
+    /// letters drawn in a grid, `text` is what gets compared and submitted
+    Letters {
+        text: String,
+        rendering: String,
+    },
     #[allow(unused)]
     Unimplemented,
 }
 
+impl Answer {
+    /// reads the letters with `ocr::decode_str`, keeping the drawing around
+    #[allow(unused)]
+    pub fn letters(rendering: impl Into<String>) -> Result<Self, SolveError> {
+        let rendering = rendering.into();
+        let text = ocr::decode_str(&rendering)?;
+
+        Ok(Self::Letters { text, rendering })
+    }
+
+    pub fn rendering(&self) -> Option<&str> {
+        match self {
+            Answer::Letters { rendering, .. } => Some(rendering),
+            _ => None,
+        }
+    }
+
+    fn integer(&self) -> Option<BigInt> {
+        match self {
+            Answer::Number(n) => Some((*n).into()),
+            Answer::Signed(n) => Some((*n).into()),
+            Answer::Big(n) => Some(n.clone()),
+            _ => None,
+        }
+    }
+}
+
+// integers compare by value whatever their variant, so `114.into()` matches a `usize` answer
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::String(a) | Answer::Letters { text: a, .. }, Answer::String(b))
+            | (Answer::String(a), Answer::Letters { text: b, .. }) => a == b,
+            (Answer::Letters { text: a, .. }, Answer::Letters { text: b, .. }) => a == b,
+            (Answer::Float(a), Answer::Float(b)) => a == b,
+            (Answer::Unimplemented, Answer::Unimplemented) => true,
+            _ => match (self.integer(), other.integer()) {
+                (Some(a), Some(b)) => a == b,
+                _ => false,
+            },
+        }
+    }
+}
+
 impl Display for Answer {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Answer::String(s) => write!(f, "{s}"),
             Answer::Number(n) => write!(f, "{n}"),
+            Answer::Signed(n) => write!(f, "{n}"),
+            Answer::Big(n) => write!(f, "{n}"),
             Answer::Float(n) => write!(f, "{n}"),
+            Answer::Letters { text, .. } => write!(f, "{text}"),
             Answer::Unimplemented => write!(f, "Unimplemented"),
         }
     }
@@ -33,21 +92,83 @@ impl From<&str> for Answer {
 }
 
 macro_rules! answer_impl {
-    ($answer:ident, $answer_type:ty, { $($type:ty),* }) => {
+    ($answer:ident, { $($type:ty),* }) => {
         $(impl From<$type> for Answer {
             fn from(n: $type) -> Self {
-                Self::$answer(n as $answer_type)
+                Self::$answer(n.into())
             }
         })*
     };
 }
 
-answer_impl!(
-    Number, u64,
-    { u8, u16, u32, u64, usize, i8, i16, i32, i64, isize }
-);
+answer_impl!(Number, { u8, u16, u32, u64 });
+
+answer_impl!(Signed, { i8, i16, i32, i64 });
+
+answer_impl!(Big, { u128, i128, BigInt, BigUint });
 
-answer_impl!(
-    Float, f64,
-    { f32, f64 }
-);
+answer_impl!(Float, { f32, f64 });
+
+// usize and isize are at most 64 bits on every supported target
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Self::Number(n as u64)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(n: isize) -> Self {
+        Self::Signed(n as i64)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_bigint::BigInt;
+
+    use super::Answer;
+
+    use indoc::indoc;
+
+    #[test]
+    fn signed() {
+        assert_eq!(Answer::from(-3i64).to_string(), "-3");
+        assert_eq!(Answer::from(-3isize), Answer::Signed(-3));
+    }
+
+    #[test]
+    fn wide() {
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(Answer::from(i128::MIN).to_string(), i128::MIN.to_string());
+
+        let big = BigInt::from(u128::MAX) * 1000u32;
+        assert_eq!(Answer::from(big.clone()).to_string(), big.to_string());
+    }
+
+    #[test]
+    fn cross_variant() {
+        assert_eq!(Answer::from(114usize), 114.into());
+        assert_eq!(Answer::from(114u128), 114.into());
+        assert_eq!(Answer::from(-2i128), (-2).into());
+        assert_ne!(Answer::from(u64::MAX), (-1i64).into());
+        assert_ne!(Answer::from(2u8), 2.0.into());
+        assert_ne!(Answer::from(2u8), "2".into());
+    }
+
+    #[test]
+    fn letters() {
+        let rendering = indoc! {"
+            #..#.###.
+            #..#..#..
+            ####..#..
+            #..#..#..
+            #..#..#..
+            #..#.###.
+        "};
+        let answer = Answer::letters(rendering).unwrap();
+
+        assert_eq!(answer, "HI".into());
+        assert_eq!(answer.to_string(), "HI");
+        assert_eq!(answer.rendering(), Some(rendering));
+    }
+}
@@ -0,0 +1,71 @@
+// the runner can't kill a solution that ran out of time, long loops should
+// call `check` now and then so the worker thread stops instead of spinning
+use std::{
+    cell::RefCell,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use super::SolveError;
+
+thread_local! {
+    static FLAG: RefCell<Option<Arc<AtomicBool>>> = const { RefCell::new(None) };
+}
+
+/// called by the runner on the worker thread, before running the solution
+pub fn install(flag: Arc<AtomicBool>) {
+    FLAG.with(|f| *f.borrow_mut() = Some(flag));
+}
+
+pub fn cancelled() -> bool {
+    FLAG.with(|f| {
+        f.borrow()
+            .as_ref()
+            .is_some_and(|flag| flag.load(Ordering::Relaxed))
+    })
+}
+
+/// errors out once the runner gave up on this thread
+pub fn check() -> Result<(), SolveError> {
+    if cancelled() {
+        Err(SolveError::new("cancelled"))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc,
+        },
+        thread,
+    };
+
+    use super::{cancelled, check, install};
+
+    #[test]
+    fn flag() {
+        let flag = Arc::new(AtomicBool::new(false));
+        let worker_flag = flag.clone();
+
+        thread::spawn(move || {
+            assert!(check().is_ok());
+
+            install(worker_flag);
+            assert!(!cancelled());
+
+            flag.store(true, Ordering::Relaxed);
+            assert!(check().is_err());
+        })
+        .join()
+        .unwrap();
+
+        // other threads are never cancelled
+        assert!(!cancelled());
+    }
+}
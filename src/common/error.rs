@@ -0,0 +1,76 @@
+use std::{
+    fmt::{self, Display},
+    num::ParseIntError,
+};
+
+use super::ParseError;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct SolveError {
+    pub day: Option<u8>,
+    pub part: Option<char>,
+    pub message: String,
+    pub span: Option<ParseError>,
+}
+
+impl SolveError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            day: None,
+            part: None,
+            message: message.into(),
+            span: None,
+        }
+    }
+
+    /// filled in by the runner
+    pub fn context(mut self, day: u8, part: char) -> Self {
+        self.day = Some(day);
+        self.part = Some(part);
+        self
+    }
+
+    /// same as `context`, for errors from the shared parse step
+    pub fn parsing(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    /// the offending input line, if this error came from a `ParseError`
+    pub fn render(&self, input: &str) -> Option<String> {
+        self.span.as_ref().map(|span| span.render(input))
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.day, self.part) {
+            (Some(day), Some(part)) => write!(f, "day {day} ({}): ", part.to_ascii_uppercase())?,
+            (Some(day), None) => write!(f, "day {day} (parsing): ")?,
+            _ => {}
+        }
+
+        match &self.span {
+            Some(span) => write!(f, "{span}"),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+impl From<ParseIntError> for SolveError {
+    fn from(e: ParseIntError) -> Self {
+        Self::new(format!("invalid number: {e}"))
+    }
+}
+
+impl From<ParseError> for SolveError {
+    fn from(e: ParseError) -> Self {
+        Self {
+            message: e.message.clone(),
+            span: Some(e),
+            ..Self::new("")
+        }
+    }
+}
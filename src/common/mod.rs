@@ -1,6 +1,13 @@
 // heavily inspired by Basicprogrammer10/advent-of-code
 mod answer;
+pub mod cancel;
+mod error;
+pub mod ocr;
+mod parse_error;
+pub mod progress;
 mod solution;
 
 pub use answer::Answer;
-pub use solution::Solution;
+pub use error::SolveError;
+pub use parse_error::ParseError;
+pub use solution::{DynSolution, ParsedInput, Solution};
@@ -0,0 +1,237 @@
+// the letters AoC draws with `#` and `.`, only the ones that showed up in a puzzle are known
+use super::SolveError;
+
+const SMALL: &[(char, &str)] = &[
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', ".###\n..#.\n..#.\n..#.\n..#.\n.###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+const LARGE: &[(char, &str)] = &[
+    (
+        'A',
+        "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'B',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.",
+    ),
+    (
+        'C',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.",
+    ),
+    (
+        'E',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'F',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'G',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#",
+    ),
+    (
+        'H',
+        "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'J',
+        "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###..",
+    ),
+    (
+        'K',
+        "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#",
+    ),
+    (
+        'L',
+        "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'N',
+        "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#",
+    ),
+    (
+        'P',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'R',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#",
+    ),
+    (
+        'X',
+        "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#",
+    ),
+    (
+        'Z',
+        "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######",
+    ),
+];
+
+/// reads the letters of a 6 or 10 rows tall drawing, glyphs are split on empty columns
+pub fn decode(grid: &[Vec<bool>]) -> Result<String, SolveError> {
+    let rows = trim_rows(grid);
+
+    let font = match rows.len() {
+        6 => SMALL,
+        10 => LARGE,
+        0 => return Err(SolveError::new("nothing to read, the grid is empty")),
+        n => return Err(SolveError::new(format!("no font is {n} rows tall"))),
+    };
+
+    let width = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+    let lit = |x: usize| rows.iter().any(|r| r.get(x) == Some(&true));
+
+    let mut text = String::new();
+    let mut x = 0;
+
+    while x < width {
+        if !lit(x) {
+            x += 1;
+            continue;
+        }
+
+        let start = x;
+        while x < width && lit(x) {
+            x += 1;
+        }
+
+        let glyph = rows
+            .iter()
+            .map(|r| (start..x).map(|x| r.get(x) == Some(&true)).collect())
+            .collect::<Vec<Vec<bool>>>();
+
+        let letter = font
+            .iter()
+            .find(|(_, pattern)| trim_columns(&parse(pattern)) == glyph)
+            .map(|(c, _)| *c)
+            .ok_or_else(|| SolveError::new(format!("unknown letter:\n{}", render(&glyph))))?;
+
+        text.push(letter);
+    }
+
+    Ok(text)
+}
+
+/// same as `decode`, `#` and `█` are lit, anything else is dark
+pub fn decode_str(grid: &str) -> Result<String, SolveError> {
+    decode(&parse(grid))
+}
+
+fn parse(grid: &str) -> Vec<Vec<bool>> {
+    grid.lines()
+        .map(|l| l.chars().map(|c| c == '#' || c == '█').collect())
+        .collect()
+}
+
+fn render(grid: &[Vec<bool>]) -> String {
+    grid.iter()
+        .map(|r| r.iter().map(|b| if *b { '#' } else { '.' }).collect())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn trim_rows(grid: &[Vec<bool>]) -> &[Vec<bool>] {
+    let lit = |r: &Vec<bool>| r.contains(&true);
+
+    match (grid.iter().position(lit), grid.iter().rposition(lit)) {
+        (Some(start), Some(end)) => &grid[start..=end],
+        _ => &[],
+    }
+}
+
+fn trim_columns(glyph: &[Vec<bool>]) -> Vec<Vec<bool>> {
+    let width = glyph.iter().map(|r| r.len()).max().unwrap_or(0);
+    let lit = |x: usize| glyph.iter().any(|r| r.get(x) == Some(&true));
+
+    let Some(start) = (0..width).find(|x| lit(*x)) else {
+        return Vec::new();
+    };
+    let end = (0..width).rfind(|x| lit(*x)).unwrap_or(start);
+
+    glyph
+        .iter()
+        .map(|r| (start..=end).map(|x| r.get(x) == Some(&true)).collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{decode, decode_str, LARGE, SMALL};
+
+    use indoc::indoc;
+
+    #[test]
+    fn small() {
+        let grid = indoc! {"
+            #..#.####.#....#.....##..
+            #..#.#....#....#....#..#.
+            ####.###..#....#....#..#.
+            #..#.#....#....#....#..#.
+            #..#.#....#....#....#..#.
+            #..#.####.####.####..##..
+        "};
+
+        assert_eq!(decode_str(grid), Ok("HELLO".into()));
+    }
+
+    #[test]
+    fn large() {
+        let grid = indoc! {"
+            #....#..#####.
+            #....#..#....#
+            .#..#...#....#
+            .#..#...#....#
+            ..##....#####.
+            ..##....#.....
+            .#..#...#.....
+            .#..#...#.....
+            #....#..#.....
+            #....#..#.....
+        "};
+
+        assert_eq!(decode_str(grid), Ok("XP".into()));
+    }
+
+    #[test]
+    fn every_letter() {
+        for font in [SMALL, LARGE] {
+            let rows = font[0].1.lines().count();
+            let mut grid = vec![String::new(); rows];
+
+            for (_, pattern) in font {
+                for (row, line) in grid.iter_mut().zip(pattern.lines()) {
+                    *row += line;
+                    *row += "..";
+                }
+            }
+
+            let letters = font.iter().map(|(c, _)| *c).collect::<String>();
+            assert_eq!(decode_str(&grid.join("\n")), Ok(letters));
+        }
+    }
+
+    #[test]
+    fn unreadable() {
+        assert!(decode(&[]).is_err());
+        assert!(decode_str("#\n#\n#").is_err());
+        assert!(decode_str("#####\n#...#\n#...#\n#...#\n#...#\n#####").is_err());
+    }
+}
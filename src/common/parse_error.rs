@@ -0,0 +1,102 @@
+use std::{
+    fmt::{self, Display},
+    ops::Range,
+};
+
+/// a parse error pointing at a place in the puzzle input, lines and columns are 0-based
+#[derive(Debug, PartialEq, Clone)]
+pub struct ParseError {
+    pub line: usize,
+    pub columns: Range<usize>,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, columns: Range<usize>, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            columns,
+            message: message.into(),
+        }
+    }
+
+    /// points at `part`, which has to be a slice of `input`
+    pub fn at(input: &str, part: &str, message: impl Into<String>) -> Self {
+        let offset = (part.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        let Some(before) = input.get(..offset) else {
+            return Self::new(0, 0..0, message);
+        };
+
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let column = before[line_start..].chars().count();
+        let len = part.chars().count().max(1);
+
+        Self::new(before.matches('\n').count(), column..column + len, message)
+    }
+
+    /// shows the offending input line with the columns underlined
+    pub fn render(&self, input: &str) -> String {
+        let line = input.lines().nth(self.line).unwrap_or("");
+        let number = (self.line + 1).to_string();
+        let pad = " ".repeat(number.len());
+
+        let underline = format!(
+            "{}{}",
+            " ".repeat(self.columns.start),
+            "^".repeat(self.columns.len().max(1))
+        );
+
+        format!(
+            "{pad}--> line {}, column {}\n{pad} |\n{number} | {line}\n{pad} | {underline} {}",
+            self.line + 1,
+            self.columns.start + 1,
+            self.message
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} (line {}, column {})",
+            self.message,
+            self.line + 1,
+            self.columns.start + 1
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ParseError;
+
+    use indoc::indoc;
+
+    const INPUT: &str = indoc! {"
+        32T3K 765
+        T5XJ5 684
+    "};
+
+    #[test]
+    fn at() {
+        let line = INPUT.lines().nth(1).unwrap();
+        let error = ParseError::at(INPUT, &line[2..3], "card X doesnt exist");
+
+        assert_eq!(error, ParseError::new(1, 2..3, "card X doesnt exist"));
+    }
+
+    #[test]
+    fn render() {
+        let error = ParseError::new(1, 0..5, "invalid hand");
+
+        assert_eq!(
+            error.render(INPUT),
+            indoc! {"
+                 --> line 2, column 1
+                  |
+                2 | T5XJ5 684
+                  | ^^^^^ invalid hand"}
+        );
+    }
+}
@@ -0,0 +1,78 @@
+// lets long running solutions say how far along they are, the runner draws it
+// as a live line. nothing is installed outside the runner, so reporting is a
+// no-op in tests and benchmarks
+use std::{
+    cell::RefCell,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+#[derive(Default)]
+pub struct Progress {
+    done: AtomicU64,
+    total: AtomicU64,
+}
+
+impl Progress {
+    pub fn reset(&self) {
+        self.done.store(0, Ordering::Relaxed);
+        self.total.store(0, Ordering::Relaxed);
+    }
+
+    /// `(done, total)`, `None` until something was reported
+    pub fn get(&self) -> Option<(u64, u64)> {
+        let total = self.total.load(Ordering::Relaxed);
+        (total > 0).then(|| (self.done.load(Ordering::Relaxed).min(total), total))
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Arc<Progress>>> = const { RefCell::new(None) };
+}
+
+/// called by the runner on the worker thread, before running the solution
+pub fn install(progress: Arc<Progress>) {
+    CURRENT.with(|p| *p.borrow_mut() = Some(progress));
+}
+
+/// `done` out of `total` units of work, cheap but not free, so call it every
+/// few thousand iterations of a hot loop rather than on each one
+pub fn report(done: u64, total: u64) {
+    CURRENT.with(|p| {
+        if let Some(progress) = p.borrow().as_ref() {
+            progress.total.store(total, Ordering::Relaxed);
+            progress.done.store(done, Ordering::Relaxed);
+        }
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use std::{sync::Arc, thread};
+
+    use super::{install, report, Progress};
+
+    #[test]
+    fn installed() {
+        let progress = Arc::new(Progress::default());
+        let worker = progress.clone();
+
+        thread::spawn(move || {
+            install(worker);
+            report(3, 4);
+        })
+        .join()
+        .unwrap();
+
+        assert_eq!(progress.get(), Some((3, 4)));
+
+        progress.reset();
+        assert_eq!(progress.get(), None);
+
+        // not installed on this thread
+        report(1, 2);
+        assert_eq!(progress.get(), None);
+    }
+}
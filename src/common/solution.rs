@@ -1,7 +1,65 @@
-use super::Answer;
+use super::{Answer, SolveError};
 
+/// the input is parsed once and shared by both parts
 pub trait Solution {
+    type Parsed;
+
+    fn day(&self) -> u8;
+    fn name(&self) -> &'static str;
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError>;
+    fn part_a(&self, parsed: &Self::Parsed) -> Result<Answer, SolveError>;
+    fn part_b(&self, parsed: &Self::Parsed) -> Result<Answer, SolveError>;
+}
+
+/// object safe side of `Solution`, which is what the registry and the runners work with
+pub trait DynSolution: Sync {
+    fn day(&self) -> u8;
     fn name(&self) -> &'static str;
-    fn part_a(&self, input: &str) -> Answer;
-    fn part_b(&self, input: &str) -> Answer;
+    fn parse<'a>(&'a self, input: &str) -> Result<Box<dyn ParsedInput + 'a>, SolveError>;
+
+    /// parses and solves in one go, for tests
+    #[cfg(test)]
+    fn solve(&self, input: &str, part: char) -> Result<Answer, SolveError> {
+        self.parse(input)?.solve(part)
+    }
+}
+
+/// an input parsed by its solution, ready to solve either part
+pub trait ParsedInput {
+    fn solve(&self, part: char) -> Result<Answer, SolveError>;
+}
+
+struct Parsed<'a, S: Solution> {
+    solution: &'a S,
+    parsed: S::Parsed,
+}
+
+impl<S: Solution> ParsedInput for Parsed<'_, S> {
+    fn solve(&self, part: char) -> Result<Answer, SolveError> {
+        match part {
+            'a' => self.solution.part_a(&self.parsed),
+            'b' => self.solution.part_b(&self.parsed),
+            _ => Err(SolveError::new(format!("there is no part {part}"))),
+        }
+    }
+}
+
+impl<S: Solution + Sync> DynSolution for S
+where
+    S::Parsed: 'static,
+{
+    fn day(&self) -> u8 {
+        Solution::day(self)
+    }
+
+    fn name(&self) -> &'static str {
+        Solution::name(self)
+    }
+
+    fn parse<'a>(&'a self, input: &str) -> Result<Box<dyn ParsedInput + 'a>, SolveError> {
+        Ok(Box::new(Parsed {
+            solution: self,
+            parsed: Solution::parse(self, input)?,
+        }))
+    }
 }
@@ -0,0 +1,216 @@
+// settings read from `aoc.toml` in the working directory and from the user's
+// `$XDG_CONFIG_HOME/aoc/config.toml` (or `~/.config/aoc/config.toml`). for each
+// setting the first of these wins:
+//
+// 1. command line flags (`--year`, `--timeout`, `--format`)
+// 2. environment variables, `.env` included (`TOKEN`, `AOC_BASE_URL`)
+// 3. the project file
+// 4. the user file
+// 5. the built-in defaults
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use serde::Deserialize;
+
+use crate::{report::Format, runner};
+
+const PROJECT_FILE: &str = "aoc.toml";
+
+#[derive(Deserialize, Default, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// the `session` cookie
+    pub token: Option<String>,
+    /// a file holding the token, used if `token` is not set
+    pub token_file: Option<PathBuf>,
+    pub year: Option<u16>,
+    pub inputs_dir: Option<PathBuf>,
+    pub answers_dir: Option<PathBuf>,
+    pub base_url: Option<String>,
+    pub format: Option<Format>,
+    /// seconds, 0 for no limit
+    pub timeout: Option<f64>,
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+impl Config {
+    /// the user file, then the project file, then the environment on top
+    pub fn load() -> Result<Self, String> {
+        let user = match user_path() {
+            Some(path) => read(&path)?,
+            None => None,
+        };
+        let project = read(Path::new(PROJECT_FILE))?;
+
+        let mut config = user.unwrap_or_default().merge(project.unwrap_or_default());
+
+        if let Ok(token) = env::var("TOKEN") {
+            config.token = Some(token);
+        }
+        if let Ok(base_url) = env::var("AOC_BASE_URL") {
+            config.base_url = Some(base_url);
+        }
+
+        config.resolve_token()?;
+
+        Ok(config)
+    }
+
+    /// `over`'s settings win over `self`'s, `token` and `token_file` count as one
+    fn merge(self, over: Self) -> Self {
+        let (token, token_file) = if over.token.is_some() || over.token_file.is_some() {
+            (over.token, over.token_file)
+        } else {
+            (self.token, self.token_file)
+        };
+
+        Self {
+            token,
+            token_file,
+            year: over.year.or(self.year),
+            inputs_dir: over.inputs_dir.or(self.inputs_dir),
+            answers_dir: over.answers_dir.or(self.answers_dir),
+            base_url: over.base_url.or(self.base_url),
+            format: over.format.or(self.format),
+            timeout: over.timeout.or(self.timeout),
+        }
+    }
+
+    fn resolve_token(&mut self) -> Result<(), String> {
+        if self.token.is_some() {
+            return Ok(());
+        }
+
+        if let Some(path) = &self.token_file {
+            let token = fs::read_to_string(path)
+                .map_err(|e| format!("could not read token file {}: {e}", path.display()))?;
+            self.token = Some(token.trim().to_string());
+        }
+
+        Ok(())
+    }
+
+    pub fn inputs_dir(&self) -> &Path {
+        self.inputs_dir.as_deref().unwrap_or(Path::new("inputs"))
+    }
+
+    pub fn answers_dir(&self) -> &Path {
+        self.answers_dir.as_deref().unwrap_or(Path::new("answers"))
+    }
+
+    pub fn base_url(&self) -> &str {
+        self.base_url
+            .as_deref()
+            .unwrap_or("https://adventofcode.com")
+    }
+}
+
+/// called once by `main`, before anything reads the config
+pub fn init(config: Config) {
+    let _ = CONFIG.set(config);
+}
+
+/// the defaults if `init` was never called, as in tests
+pub fn get() -> &'static Config {
+    CONFIG.get_or_init(Config::default)
+}
+
+fn user_path() -> Option<PathBuf> {
+    let dir = match env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from(env::var_os("HOME")?).join(".config"),
+    };
+
+    Some(dir.join("aoc").join("config.toml"))
+}
+
+fn read(path: &Path) -> Result<Option<Config>, String> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(format!("could not read {}: {e}", path.display())),
+    };
+
+    parse(&content)
+        .map(Some)
+        .map_err(|e| format!("{}: {e}", path.display()))
+}
+
+fn parse(content: &str) -> Result<Config, String> {
+    let config: Config = toml::from_str(content).map_err(|e| e.message().to_string())?;
+
+    if let Some(timeout) = config.timeout {
+        runner::time_limit(timeout)?;
+    }
+
+    Ok(config)
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::{Path, PathBuf};
+
+    use super::{parse, Config};
+    use crate::report::Format;
+
+    use indoc::indoc;
+
+    #[test]
+    fn parsed() {
+        let config = parse(indoc! {r#"
+            token_file = "secrets/token"
+            year = 2022
+            inputs_dir = "data/inputs"
+            format = "markdown"
+            timeout = 5
+        "#})
+        .unwrap();
+
+        assert_eq!(
+            config,
+            Config {
+                token_file: Some(PathBuf::from("secrets/token")),
+                year: Some(2022),
+                inputs_dir: Some(PathBuf::from("data/inputs")),
+                format: Some(Format::Markdown),
+                timeout: Some(5.0),
+                ..Config::default()
+            }
+        );
+
+        assert_eq!(config.inputs_dir(), Path::new("data/inputs"));
+        assert_eq!(config.answers_dir(), Path::new("answers"));
+        assert_eq!(config.base_url(), "https://adventofcode.com");
+    }
+
+    #[test]
+    fn rejected() {
+        assert!(parse("yaer = 2022").is_err());
+        assert!(parse("year = \"2022\"").is_err());
+        assert!(parse("format = \"html\"").is_err());
+        assert!(parse("timeout = 1e30").is_err());
+        assert!(parse("timeout = -1").is_err());
+    }
+
+    #[test]
+    fn merged() {
+        let user = parse("year = 2022\ntimeout = 10\ntoken = \"user\"").unwrap();
+        let project = parse("year = 2023").unwrap();
+
+        let config = user.merge(project);
+
+        assert_eq!(config.year, Some(2023));
+        assert_eq!(config.timeout, Some(10.0));
+        assert_eq!(config.token.as_deref(), Some("user"));
+
+        let project = parse("token_file = \"token\"").unwrap();
+        let config = config.merge(project);
+
+        assert_eq!(config.token, None);
+        assert_eq!(config.token_file, Some(PathBuf::from("token")));
+    }
+}
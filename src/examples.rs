@@ -0,0 +1,269 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{html, input};
+
+const EXAMPLES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/examples");
+
+/// an example input with the answers the puzzle text gives for it
+#[derive(Debug, PartialEq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub answers: Vec<(char, String)>,
+}
+
+pub fn examples_dir(year: u16, day: u8) -> PathBuf {
+    PathBuf::from(EXAMPLES_DIR)
+        .join(year.to_string())
+        .join(format!("day_{day:02}"))
+}
+
+/// pulls the examples out of a puzzle page, one `<article>` per part. each
+/// `<pre><code>` block is paired with the first `<code><em>` after it, if that
+/// comes before the next block, blocks without one are skipped. a part with no
+/// blocks at all gives the last example its answer, the last `<code><em>`
+pub fn extract(page: &str) -> Vec<Example> {
+    let mut examples: Vec<Example> = Vec::new();
+
+    for (article, part) in articles(page).into_iter().zip(['a', 'b']) {
+        let blocks = blocks(article);
+
+        if blocks.is_empty() {
+            let answer = between_all(article, "<code><em>", "</em></code>").pop();
+
+            if let (Some(answer), Some(example)) = (answer, examples.last_mut()) {
+                example.answers.push((part, html::strip_tags(answer)));
+            }
+
+            continue;
+        }
+
+        for (block, after) in blocks {
+            let Some(&answer) = between_all(after, "<code><em>", "</em></code>").first() else {
+                continue;
+            };
+
+            examples.push(Example {
+                name: format!("example_{}", examples.len() + 1),
+                input: input::normalize(&html::strip_tags(block)),
+                answers: vec![(part, html::strip_tags(answer))],
+            });
+        }
+    }
+
+    examples
+}
+
+/// every `<pre><code>` block, with the text between it and the next one
+fn blocks(article: &str) -> Vec<(&str, &str)> {
+    const START: &str = "<pre><code>";
+    const END: &str = "</code></pre>";
+
+    let mut found = Vec::new();
+    let mut rest = article;
+
+    while let Some(i) = rest.find(START) {
+        rest = &rest[i + START.len()..];
+
+        let Some(j) = rest.find(END) else {
+            break;
+        };
+
+        let block = &rest[..j];
+        rest = &rest[j + END.len()..];
+
+        let next = rest.find(START).unwrap_or(rest.len());
+        found.push((block, &rest[..next]));
+    }
+
+    found
+}
+
+/// writes `<name>.txt` and one `<name>.<part>` answer file per answer.
+/// nothing is written if one of them exists, unless `force` is set
+pub fn save(
+    year: u16,
+    day: u8,
+    examples: &[Example],
+    force: bool,
+) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let dir = examples_dir(year, day);
+    write_all(&dir, examples, force)?;
+
+    Ok(dir)
+}
+
+fn write_all(
+    dir: &Path,
+    examples: &[Example],
+    force: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut files = Vec::new();
+
+    for example in examples {
+        files.push((dir.join(format!("{}.txt", example.name)), &example.input));
+
+        for (part, answer) in &example.answers {
+            files.push((dir.join(format!("{}.{part}", example.name)), answer));
+        }
+    }
+
+    if !force {
+        if let Some((path, _)) = files.iter().find(|(path, _)| path.exists()) {
+            return Err(format!(
+                "{} already exists, pass --force to overwrite",
+                path.display()
+            )
+            .into());
+        }
+    }
+
+    fs::create_dir_all(dir)?;
+
+    for (path, content) in files {
+        fs::write(path, content)?;
+    }
+
+    Ok(())
+}
+
+fn articles(page: &str) -> Vec<&str> {
+    between_all(page, "<article", "</article>")
+}
+
+fn between_all<'a>(text: &'a str, start: &str, end: &str) -> Vec<&'a str> {
+    let mut found = Vec::new();
+    let mut rest = text;
+
+    while let Some(i) = rest.find(start) {
+        rest = &rest[i + start.len()..];
+
+        let Some(j) = rest.find(end) else {
+            break;
+        };
+
+        found.push(&rest[..j]);
+        rest = &rest[j + end.len()..];
+    }
+
+    found
+}
+
+/// runs one example against its registered solution, called by the tests
+/// build.rs generates
+#[cfg(test)]
+fn check(year: u16, day: u8, name: &str, part: char) {
+    let dir = examples_dir(year, day);
+
+    let input = fs::read_to_string(dir.join(format!("{name}.txt"))).unwrap();
+    let expected = fs::read_to_string(dir.join(format!("{name}.{part}"))).unwrap();
+
+    let solution = crate::solutions::get(year, day).unwrap();
+    let input = input::normalize(&input);
+
+    assert_eq!(
+        solution.solve(&input, part).map(|a| a.to_string()),
+        Ok(expected.trim().to_string())
+    );
+}
+
+#[cfg(test)]
+mod generated {
+    include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs, process};
+
+    use super::{extract, write_all, Example};
+
+    const PAGE: &str = include_str!("../fixtures/puzzle_page.html");
+
+    #[test]
+    fn no_overwrite() {
+        let dir = env::temp_dir().join(format!("aoc-examples-{}", process::id()));
+        let examples = [Example {
+            name: "example_1".into(),
+            input: "1abc2".into(),
+            answers: vec![('a', "12".into())],
+        }];
+
+        write_all(&dir, &examples, false).unwrap();
+        fs::write(dir.join("example_1.a"), "edited").unwrap();
+
+        assert!(write_all(&dir, &examples, false).is_err());
+        assert_eq!(
+            fs::read_to_string(dir.join("example_1.a")).unwrap(),
+            "edited"
+        );
+
+        write_all(&dir, &examples, true).unwrap();
+        assert_eq!(fs::read_to_string(dir.join("example_1.a")).unwrap(), "12");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn both_parts() {
+        assert_eq!(
+            extract(PAGE),
+            vec![
+                Example {
+                    name: "example_1".into(),
+                    input: "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet".into(),
+                    answers: vec![('a', "142".into())],
+                },
+                Example {
+                    name: "example_2".into(),
+                    input: "two1nine\neightwothree\nabcone2threexyz\nxtwone3four\n4nineeightseven2\nzoneight234\n7pqrstsixteen".into(),
+                    answers: vec![('b', "281".into())],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn several_examples() {
+        let page = include_str!("../fixtures/two_examples.html");
+
+        assert_eq!(
+            extract(page),
+            vec![
+                Example {
+                    name: "example_1".into(),
+                    input: "-L|F7\n7S-7|\nL|7||\n-L-J|\nL|-JF".into(),
+                    answers: vec![('a', "4".into())],
+                },
+                Example {
+                    name: "example_2".into(),
+                    input: "7-F7-\n.FJ|7\nSJLL7\n|F--J\nLJ.LJ".into(),
+                    answers: vec![('a', "8".into())],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn shared_example() {
+        let page = "<article><pre><code>1 &lt; 2\n</code></pre><code><em>3</em></code></article>\
+                    <article><p>Now <code><em>12</em></code>.</p></article>";
+
+        assert_eq!(
+            extract(page),
+            vec![Example {
+                name: "example_1".into(),
+                input: "1 < 2".into(),
+                answers: vec![('a', "3".into()), ('b', "12".into())],
+            }]
+        );
+    }
+
+    #[test]
+    fn unsolved() {
+        assert_eq!(extract("<main><p>nothing here</p></main>"), vec![]);
+    }
+}
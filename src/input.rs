@@ -0,0 +1,156 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{client::Client, config};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheMode {
+    /// read from the cache, fetching and storing the input if it is missing
+    Use,
+    /// always fetch, overwriting the cached input
+    Refresh,
+    /// always fetch, never touching the cache
+    Bypass,
+}
+
+type Fetched = Result<String, Box<dyn std::error::Error>>;
+
+pub fn cache_path(year: u16, day: u8) -> PathBuf {
+    path_in(config::get().inputs_dir(), year, day)
+}
+
+fn path_in(inputs_dir: &Path, year: u16, day: u8) -> PathBuf {
+    inputs_dir
+        .join(year.to_string())
+        .join(format!("day_{day:02}.txt"))
+}
+
+pub fn load(year: u16, day: u8, mode: CacheMode) -> Result<String, Box<dyn std::error::Error>> {
+    let fetch = Client::from_config().map(|client| move || client.input(year, day));
+
+    load_from(&cache_path(year, day), day, mode, fetch)
+}
+
+/// `load` with the cache file and the download passed in, `fetch` is `None`
+/// without a session token
+fn load_from(
+    path: &Path,
+    day: u8,
+    mode: CacheMode,
+    fetch: Option<impl FnOnce() -> Fetched>,
+) -> Fetched {
+    if mode == CacheMode::Use {
+        match fs::read_to_string(path) {
+            Ok(input) => return Ok(normalize(&input)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(format!("could not read {}: {e}", path.display()).into()),
+        }
+    }
+
+    let Some(fetch) = fetch else {
+        return Err(format!(
+            "input for day {day} is not cached in {} and no session token is set",
+            path.display()
+        )
+        .into());
+    };
+
+    let input = fetch().map_err(|e| format!("could not fetch input for day {day}: {e}"))?;
+
+    if mode != CacheMode::Bypass {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(path, &input)?;
+    }
+
+    Ok(input)
+}
+
+/// reads a hand-made input from a file, or from stdin if `path` is `-`
+pub fn read(path: &Path) -> Result<String, Box<dyn std::error::Error>> {
+    let input = if path == Path::new("-") {
+        io::read_to_string(io::stdin()).map_err(|e| format!("could not read stdin: {e}"))?
+    } else {
+        fs::read_to_string(path).map_err(|e| format!("could not read {}: {e}", path.display()))?
+    };
+
+    Ok(normalize(&input))
+}
+
+/// every input goes through this, wherever it comes from
+pub fn normalize(input: &str) -> String {
+    input.trim().replace('\r', "")
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs, path::Path, process};
+
+    use super::{load_from, normalize, path_in, CacheMode, Fetched};
+
+    fn fetch(input: &'static str) -> Option<impl FnOnce() -> Fetched> {
+        Some(move || Ok(input.to_string()))
+    }
+
+    fn offline() -> Option<fn() -> Fetched> {
+        None
+    }
+
+    #[test]
+    fn cache_modes() {
+        let dir = env::temp_dir().join(format!("aoc-inputs-{}", process::id()));
+        let path = path_in(&dir, 2023, 1);
+        assert_eq!(path, dir.join("2023").join("day_01.txt"));
+
+        let load = |mode, fetched| load_from(&path, 1, mode, fetch(fetched)).unwrap();
+
+        // fetched and stored, then read back without fetching
+        assert_eq!(load(CacheMode::Use, "fetched"), "fetched");
+        assert_eq!(fs::read_to_string(&path).unwrap(), "fetched");
+        assert_eq!(load(CacheMode::Use, "not used"), "fetched");
+
+        assert_eq!(load(CacheMode::Bypass, "bypassed"), "bypassed");
+        assert_eq!(fs::read_to_string(&path).unwrap(), "fetched");
+
+        assert_eq!(load(CacheMode::Refresh, "refreshed"), "refreshed");
+        assert_eq!(fs::read_to_string(&path).unwrap(), "refreshed");
+
+        // a cached input is read without a token, only refreshing needs one
+        fs::write(&path, "  edited\r\n").unwrap();
+        assert_eq!(
+            load_from(&path, 1, CacheMode::Use, offline()).unwrap(),
+            "edited"
+        );
+        assert!(load_from(&path, 1, CacheMode::Refresh, offline()).is_err());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn not_cached() {
+        let path = Path::new("missing").join("2023").join("day_02.txt");
+        let error = load_from(&path, 2, CacheMode::Use, offline()).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "input for day 2 is not cached in {} and no session token is set",
+                path.display()
+            )
+        );
+    }
+
+    #[test]
+    fn normalized() {
+        assert_eq!(
+            normalize("\n1abc2\r\npqr3stu8vwx\r\n\n"),
+            "1abc2\npqr3stu8vwx"
+        );
+        assert_eq!(normalize("  a\n  b  "), "a\n  b");
+        assert_eq!(normalize(""), "");
+    }
+}
@@ -1,71 +1,401 @@
-use std::time::Instant;
+use std::{path::Path, process::ExitCode};
 
+use bench::BenchOptions;
+use clap::Parser;
+use cli::{Cli, Command, Days};
+use client::Client;
+use common::{Answer, DynSolution, SolveError};
+use config::Config;
+use input::CacheMode;
+use report::{Format, Record};
+use runner::{Outcome, RunOptions};
+use submit::Submission;
+
+mod answers;
+mod bench;
+mod cli;
+mod client;
 mod common;
+mod config;
+mod examples;
+mod html;
+mod input;
+mod memory;
+mod readme;
+mod report;
+mod runner;
+mod scaffold;
 mod solutions;
+mod submit;
 
-fn main() {
+fn main() -> ExitCode {
     let _ = dotenvy::dotenv();
 
-    let args = std::env::args().collect::<Vec<_>>();
+    // parsed first so `--help` works even if the registry is broken
+    let cli = Cli::parse();
 
-    let day = args
-        .get(1)
-        .and_then(|n| n.parse::<u8>().ok())
-        .expect("day is not a number");
+    if let Err(e) = solutions::check() {
+        eprintln!("[-] {e}");
+        return ExitCode::FAILURE;
+    }
 
-    let part = args
-        .get(2)
-        .and_then(|n| n.chars().next())
-        .expect("part is not a letter");
+    match Config::load() {
+        Ok(config) => config::init(config),
+        Err(e) => {
+            eprintln!("[-] {e}");
+            return ExitCode::FAILURE;
+        }
+    }
 
-    let Some(solution) = solutions::SOLUTIONS.get((day - 1) as usize) else {
-        eprintln!("[-] Day {} not implemented", day);
-        return;
+    let config = config::get();
+    let mode = cli.cache_mode();
+    let year = cli.year(config);
+    let options = match cli.run_options(config) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("[-] {e}");
+            return ExitCode::FAILURE;
+        }
     };
 
-    let input = load(day).unwrap();
+    let ok = match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+        } => run(
+            year,
+            day,
+            part,
+            input.as_deref(),
+            mode,
+            options,
+            cli::format(format, config),
+        ),
+        Command::All { jobs, format } => {
+            let format = cli::format(format, config);
+            runner::run_all(year, mode, options, cli::jobs(jobs), format)
+        }
+        Command::Bench {
+            days,
+            part,
+            warmup,
+            iterations,
+            json,
+            save_baseline,
+            baseline,
+            input,
+            threshold,
+        } => {
+            let day = match days {
+                Days::All => None,
+                Days::Day(day) => Some(day),
+            };
+
+            let options = BenchOptions {
+                warmup,
+                iterations,
+                json,
+                save_baseline,
+                baseline,
+                input,
+                threshold,
+                limit: options.limit,
+            };
+
+            bench::bench(year, day, &cli::parts(part), &options, mode)
+        }
+        Command::Fetch { day } => fetch(year, day, mode),
+        Command::FetchExample { day, force } => fetch_example(year, day, force),
+        Command::Submit { day, part } => submit(year, day, part, mode, options),
+        Command::Record { day, part, answer } => record(year, day, part, answer, mode, options),
+        Command::Verify { jobs } => runner::verify(year, mode, options, cli::jobs(jobs)),
+        Command::Readme { path, jobs } => {
+            readme::update(year, &path, mode, options, cli::jobs(jobs))
+        }
+        Command::New { day, name } => match scaffold::new_day(year, day, name) {
+            Ok(path) => {
+                println!("[*] Created {}", path.display());
+                true
+            }
+            Err(e) => {
+                eprintln!("[-] {e}");
+                false
+            }
+        },
+    };
+
+    if ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn load(year: u16, day: u8, path: Option<&Path>, mode: CacheMode) -> Option<String> {
+    let input = match path {
+        Some(path) => input::read(path),
+        None => input::load(year, day, mode),
+    };
+
+    input.map_err(|e| eprintln!("[-] {e}")).ok()
+}
+
+/// parses the input once and runs each part on it, `None` for the parts that failed
+fn solve(
+    solution: &'static dyn DynSolution,
+    day: u8,
+    parts: &[char],
+    input: &str,
+    options: RunOptions,
+) -> Vec<Option<Answer>> {
+    println!("[*] Running: {} ({day})", solution.name());
+
+    let result = runner::run_day(solution, day, parts, input, options);
+
+    let Some(time) = result.parse_time else {
+        // every part failed the same way
+        if let Some(first) = result.parts.into_iter().next() {
+            fail(first.outcome, format!("day {day} (parsing)"), input);
+        }
+
+        return vec![None; parts.len()];
+    };
 
     println!(
-        "[*] Running: {} ({}-{})",
-        solution.name(),
-        day,
-        part.to_uppercase()
+        "[*] Parsed (took {})",
+        runner::format_time(time.as_nanos() as f64)
     );
 
-    let start = Instant::now();
-    let out = match part.to_ascii_lowercase() {
-        'a' => solution.part_a(&input),
-        'b' => solution.part_b(&input),
-        _ => return eprintln!("[-] Invalid Part {}", part),
+    parts
+        .iter()
+        .zip(result.parts)
+        .map(|(part, result)| {
+            let part = part.to_ascii_uppercase();
+            let mut time = runner::format_time(result.time.as_nanos() as f64);
+            if let Some(usage) = result.memory {
+                time += &format!(", {usage}");
+            }
+
+            match result.outcome {
+                Outcome::Solved(answer) => {
+                    println!("[*] Part {part}: {answer} (took {time})");
+
+                    if let Some(rendering) = answer.rendering() {
+                        println!("{}", rendering.trim_end());
+                    }
+
+                    Some(answer)
+                }
+                Outcome::Unimplemented => {
+                    println!("[*] Part {part}: unimplemented");
+                    Some(Answer::Unimplemented)
+                }
+                outcome => {
+                    fail(outcome, format!("day {day} ({part})"), input);
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+fn fail(outcome: Outcome, label: String, input: &str) {
+    match outcome {
+        Outcome::Failed(e) => report(e, input),
+        outcome => eprintln!("[-] {label}: {}", outcome.cell()),
+    }
+}
+
+fn report(e: SolveError, input: &str) {
+    eprintln!("[-] {e}");
+
+    if let Some(rendered) = e.render(input) {
+        eprintln!("{rendered}");
+    }
+}
+
+fn run(
+    year: u16,
+    day: u8,
+    part: Option<char>,
+    path: Option<&Path>,
+    mode: CacheMode,
+    options: RunOptions,
+    format: Format,
+) -> bool {
+    let solution = match solutions::get(year, day) {
+        Ok(solution) => solution,
+        Err(e) => {
+            eprintln!("[-] {e}");
+            return false;
+        }
     };
 
-    let time = start.elapsed().as_nanos();
-    println!("[*] Out: {} (took {})", out, format_time(time));
+    let Some(input) = load(year, day, path, mode) else {
+        return false;
+    };
+
+    let parts = cli::parts(part);
+
+    if format == Format::Text {
+        return solve(solution, day, &parts, &input, options)
+            .iter()
+            .all(Option::is_some);
+    }
+
+    // only the records go to stdout, failures are reported on stderr
+    let result = runner::run_day(solution, day, &parts, &input, options);
+    let mut records = Vec::new();
+    let mut ok = true;
+
+    for (&part, result) in parts.iter().zip(&result.parts) {
+        if result.outcome.is_error() {
+            let label = format!("day {day} ({})", part.to_ascii_uppercase());
+            eprintln!("[-] {label}: {}", result.outcome.cell());
+            ok = false;
+        }
+
+        records.push(Record::new(solution, part, result));
+    }
+
+    print!("{}", report::render(format, &records));
+
+    ok
 }
 
-fn load(day: u8) -> Result<String, Box<dyn std::error::Error>> {
-    let token = std::env::var("TOKEN").expect("TOKEN is not set");
-
-    Ok(
-        ureq::get(format!("https://adventofcode.com/2023/day/{day}/input").as_str())
-            .set("Cookie", format!("session={token}").as_str())
-            .call()?
-            .into_string()?
-            .trim()
-            .replace('\r', ""),
-    )
+fn fetch(year: u16, day: u8, mode: CacheMode) -> bool {
+    match input::load(year, day, mode) {
+        Ok(_) => {
+            let path = input::cache_path(year, day);
+            println!("[*] Input for day {day} is cached in {}", path.display());
+            true
+        }
+        Err(e) => {
+            eprintln!("[-] {e}");
+            false
+        }
+    }
 }
 
-fn format_time(nanos: u128) -> String {
-    const TIME_UNITS: &[&str] = &["ns", "μs", "ms", "s"];
+fn fetch_example(year: u16, day: u8, force: bool) -> bool {
+    let Some(client) = Client::from_config() else {
+        eprintln!("[-] No session token is set");
+        return false;
+    };
 
-    let mut time = nanos;
-    for i in TIME_UNITS {
-        if time < 1000 {
-            return format!("{}{}", time, i);
+    let page = match client.puzzle(year, day) {
+        Ok(page) => page,
+        Err(e) => {
+            eprintln!("[-] Could not fetch the puzzle for day {day}: {e}");
+            return false;
+        }
+    };
+
+    let examples = examples::extract(&page);
+    if examples.is_empty() {
+        eprintln!("[-] No examples with answers found for day {day}");
+        return false;
+    }
+
+    match examples::save(year, day, &examples, force) {
+        Ok(dir) => {
+            println!("[*] Saved {} examples to {}", examples.len(), dir.display());
+            true
+        }
+        Err(e) => {
+            eprintln!("[-] Could not save examples: {e}");
+            false
         }
-        time /= 1000;
     }
+}
 
-    format!("{}{}", time, TIME_UNITS.last().unwrap())
+fn submit(year: u16, day: u8, part: char, mode: CacheMode, options: RunOptions) -> bool {
+    let solution = match solutions::get(year, day) {
+        Ok(solution) => solution,
+        Err(e) => {
+            eprintln!("[-] {e}");
+            return false;
+        }
+    };
+
+    let Some(answer) = load(year, day, None, mode)
+        .and_then(|i| solve(solution, day, &[part], &i, options).remove(0))
+    else {
+        return false;
+    };
+
+    if answer == Answer::Unimplemented {
+        eprintln!("[-] Refusing to submit an unimplemented answer");
+        return false;
+    }
+
+    let Some(client) = Client::from_config() else {
+        eprintln!("[-] No session token is set");
+        return false;
+    };
+
+    match submit::submit(&client, year, day, part, &answer.to_string()) {
+        Ok(reply) => {
+            println!("[*] Submitted {answer}: {reply}");
+
+            if reply != Submission::Correct {
+                return false;
+            }
+
+            if let Err(e) = answers::record(year, day, part, answer.to_string()) {
+                eprintln!("[-] Could not record answer: {e}");
+            }
+
+            true
+        }
+        Err(e) => {
+            eprintln!("[-] Could not submit answer: {e}");
+            false
+        }
+    }
+}
+
+fn record(
+    year: u16,
+    day: u8,
+    part: char,
+    answer: Option<String>,
+    mode: CacheMode,
+    options: RunOptions,
+) -> bool {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let solution = match solutions::get(year, day) {
+                Ok(solution) => solution,
+                Err(e) => {
+                    eprintln!("[-] {e}");
+                    return false;
+                }
+            };
+
+            match load(year, day, None, mode)
+                .and_then(|i| solve(solution, day, &[part], &i, options).remove(0))
+            {
+                Some(Answer::Unimplemented) | None => return false,
+                Some(answer) => answer.to_string(),
+            }
+        }
+    };
+
+    match answers::record(year, day, part, answer.clone()) {
+        Ok(()) => {
+            println!(
+                "[*] Recorded {answer} for day {day} ({})",
+                part.to_uppercase()
+            );
+            true
+        }
+        Err(e) => {
+            eprintln!("[-] Could not record answer: {e}");
+            false
+        }
+    }
 }
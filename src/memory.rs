@@ -0,0 +1,186 @@
+// counts what the solutions allocate, built with `--features count-alloc`. the
+// counters are per thread, so parts running side by side don't mix. without
+// the feature nothing installs the allocator and `measure` returns `None`
+#![cfg_attr(not(feature = "count-alloc"), allow(dead_code))]
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt::{self, Display},
+};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq)]
+pub struct Usage {
+    /// total bytes allocated, a `realloc` counts its new size
+    pub bytes: u64,
+    pub count: u64,
+    /// most bytes live at once, freeing what was allocated before can't lower it below 0
+    pub peak: u64,
+}
+
+impl Display for Usage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} peak, {} in {} allocs",
+            format_bytes(self.peak),
+            format_bytes(self.bytes),
+            self.count
+        )
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Counters {
+    enabled: bool,
+    bytes: u64,
+    count: u64,
+    live: i64,
+    peak: i64,
+}
+
+impl Counters {
+    const OFF: Self = Self {
+        enabled: false,
+        bytes: 0,
+        count: 0,
+        live: 0,
+        peak: 0,
+    };
+
+    fn alloc(&mut self, size: usize) {
+        self.bytes += size as u64;
+        self.count += 1;
+        self.live += size as i64;
+        self.peak = self.peak.max(self.live);
+    }
+}
+
+thread_local! {
+    static COUNTERS: Cell<Counters> = const { Cell::new(Counters::OFF) };
+}
+
+/// only counts on threads inside `measure`
+pub struct Counting;
+
+#[cfg(feature = "count-alloc")]
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+fn update(f: impl FnOnce(&mut Counters)) {
+    // gone while the thread is torn down, nothing is measured then anyway
+    let _ = COUNTERS.try_with(|c| {
+        let mut counters = c.get();
+        if counters.enabled {
+            f(&mut counters);
+            c.set(counters);
+        }
+    });
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        update(|c| c.alloc(layout.size()));
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        update(|c| c.alloc(layout.size()));
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        update(|c| c.live -= layout.size() as i64);
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        update(|c| {
+            c.live -= layout.size() as i64;
+            c.alloc(new_size);
+        });
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// whether the binary was built with the counting allocator
+pub const ENABLED: bool = cfg!(feature = "count-alloc");
+
+/// what `f` allocated on this thread, `None` without the counting allocator
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Option<Usage>) {
+    if !ENABLED {
+        return (f(), None);
+    }
+
+    COUNTERS.set(Counters {
+        enabled: true,
+        ..Counters::OFF
+    });
+    let out = f();
+    let counters = COUNTERS.replace(Counters::OFF);
+
+    let usage = Usage {
+        bytes: counters.bytes,
+        count: counters.count,
+        peak: counters.peak.max(0) as u64,
+    };
+
+    (out, Some(usage))
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: &[&str] = &["KiB", "MiB", "GiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes}B");
+    }
+
+    let mut size = bytes as f64;
+    for i in UNITS {
+        size /= 1024.0;
+        if size < 1024.0 {
+            return format!("{size:.1}{i}");
+        }
+    }
+
+    format!("{size:.1}{}", UNITS.last().unwrap())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{format_bytes, measure, Counters, Usage, COUNTERS};
+
+    #[test]
+    fn counters() {
+        let mut counters = Counters::OFF;
+        counters.alloc(100);
+        counters.alloc(50);
+        counters.live -= 150;
+        counters.alloc(20);
+
+        assert_eq!(counters.bytes, 170);
+        assert_eq!(counters.count, 3);
+        assert_eq!(counters.peak, 150);
+    }
+
+    #[test]
+    fn measured() {
+        let (v, usage) = measure(|| vec![0u8; 4096]);
+
+        assert_eq!(v.len(), 4096);
+        assert!(!COUNTERS.get().enabled);
+
+        if let Some(Usage { bytes, count, peak }) = usage {
+            assert!(bytes >= 4096 && count >= 1 && peak >= 4096);
+        }
+    }
+
+    #[test]
+    fn bytes() {
+        assert_eq!(format_bytes(512), "512B");
+        assert_eq!(format_bytes(1536), "1.5KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0MiB");
+    }
+}
@@ -0,0 +1,196 @@
+// keeps the progress table in the README up to date, everything outside the
+// markers is left as is
+use std::{fs, path::Path};
+
+use crate::{
+    answers::AnswerStore,
+    input::CacheMode,
+    report::{markdown_cell, Record},
+    runner::{self, format_time, RunOptions},
+};
+
+const START: &str = "<!-- progress:start -->";
+const END: &str = "<!-- progress:end -->";
+
+/// runs every day and writes the table between the markers of `path`
+pub fn update(year: u16, path: &Path, mode: CacheMode, options: RunOptions, jobs: usize) -> bool {
+    let readme = match fs::read_to_string(path) {
+        Ok(readme) => readme,
+        Err(e) => {
+            eprintln!("[-] Could not read {}: {e}", path.display());
+            return false;
+        }
+    };
+
+    let records = match runner::run_records(year, mode, options, jobs) {
+        Ok(records) => records,
+        Err(e) => {
+            eprintln!("[-] {e}");
+            return false;
+        }
+    };
+
+    let store = AnswerStore::load(year)
+        .map_err(|e| eprintln!("[-] Not checking recorded answers, {e}"))
+        .ok();
+    let recorded = |day, part| store.as_ref().and_then(|s| s.get(day, part));
+
+    let table = table(year, &records, recorded);
+
+    let readme = match replace_section(&readme, &table) {
+        Ok(readme) => readme,
+        Err(e) => {
+            eprintln!("[-] {}: {e}", path.display());
+            return false;
+        }
+    };
+
+    if let Err(e) = fs::write(path, readme) {
+        eprintln!("[-] Could not write {}: {e}", path.display());
+        return false;
+    }
+
+    println!("[*] Updated the progress table in {}", path.display());
+    true
+}
+
+/// one row per day, a part earns its star once its answer matches the one
+/// recorded for it. solved parts without a recorded answer are unverified
+fn table<'a>(
+    year: u16,
+    records: &[Record],
+    recorded: impl Fn(u8, char) -> Option<&'a str>,
+) -> String {
+    let mut out = "| Day | Title | Stars | Part A | Part B |\n".to_string();
+    out += "|----:|-------|-------|-------:|-------:|\n";
+
+    let mut total = 0;
+    let mut unverified = 0;
+
+    for day in records.chunk_by(|a, b| a.day == b.day) {
+        let first = &day[0];
+        let mut stars = String::new();
+        let mut cells = Vec::new();
+
+        for record in day {
+            let time = record.time_ns.map_or("-".into(), |t| format_time(t as f64));
+            let expected = recorded(record.day, record.part);
+
+            cells.push(match (record.status, record.answer.as_deref()) {
+                ("solved", Some(answer)) if expected == Some(answer) => {
+                    stars.push('⭐');
+                    total += 1;
+                    time
+                }
+                ("solved", _) if expected.is_some() => "wrong answer".to_string(),
+                ("solved", _) => {
+                    unverified += 1;
+                    format!("{time} (unverified)")
+                }
+                ("unimplemented", _) => "-".to_string(),
+                (status, _) => status.to_string(),
+            });
+        }
+
+        out += &format!(
+            "| {} | [{}](https://adventofcode.com/{year}/day/{}) | {stars} | {} |\n",
+            first.day,
+            markdown_cell(first.title),
+            first.day,
+            cells.join(" | "),
+        );
+    }
+
+    out += &format!("\n{total} stars");
+    if unverified > 0 {
+        out += &format!(", {unverified} unverified");
+    }
+    out.push('\n');
+    out
+}
+
+/// swaps what is between the markers for `section`, keeping the markers
+fn replace_section(readme: &str, section: &str) -> Result<String, String> {
+    let missing = || format!("add {START} and {END} where the table should go");
+
+    let start = readme.find(START).ok_or_else(missing)? + START.len();
+    let end = readme[start..].find(END).ok_or_else(missing)? + start;
+
+    Ok(format!("{}\n{section}{}", &readme[..start], &readme[end..]))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{replace_section, table};
+    use crate::report::Record;
+
+    use indoc::indoc;
+
+    fn record(day: u8, part: char, answer: Option<&str>, status: &'static str) -> Record {
+        Record {
+            day,
+            title: ["Trebuchet?!", "Cube Conundrum", "Gear Ratios"][day as usize - 1],
+            part,
+            answer: answer.map(Into::into),
+            status,
+            time_ns: answer.map(|_| 1500),
+        }
+    }
+
+    #[test]
+    fn stars() {
+        let records = [
+            record(1, 'a', Some("142"), "solved"),
+            record(1, 'b', Some("281"), "solved"),
+            record(2, 'a', Some("7"), "solved"),
+            record(2, 'b', None, "unimplemented"),
+            record(3, 'a', Some("4361"), "solved"),
+            record(3, 'b', None, "failed"),
+        ];
+        let recorded = |day, part| match (day, part) {
+            (1, 'a') => Some("142"),
+            (1, 'b') => Some("281"),
+            (2, 'a') => Some("8"),
+            _ => None,
+        };
+
+        assert_eq!(
+            table(2023, &records, recorded),
+            indoc! {"
+                | Day | Title | Stars | Part A | Part B |
+                |----:|-------|-------|-------:|-------:|
+                | 1 | [Trebuchet?!](https://adventofcode.com/2023/day/1) | ⭐⭐ | 1.500μs | 1.500μs |
+                | 2 | [Cube Conundrum](https://adventofcode.com/2023/day/2) |  | wrong answer | - |
+                | 3 | [Gear Ratios](https://adventofcode.com/2023/day/3) |  | 1.500μs (unverified) | failed |
+
+                2 stars, 1 unverified
+            "}
+        );
+    }
+
+    #[test]
+    fn section() {
+        let readme = indoc! {"
+            # Title
+            <!-- progress:start -->
+            old table
+            <!-- progress:end -->
+            rest
+        "};
+
+        assert_eq!(
+            replace_section(readme, "new table\n"),
+            Ok(indoc! {"
+                # Title
+                <!-- progress:start -->
+                new table
+                <!-- progress:end -->
+                rest
+            "}
+            .to_string())
+        );
+
+        assert!(replace_section("# Title\n", "new table\n").is_err());
+        assert!(replace_section("<!-- progress:end --><!-- progress:start -->", "").is_err());
+    }
+}
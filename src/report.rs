@@ -0,0 +1,192 @@
+// run results in formats meant for other programs, one record per part
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    common::DynSolution,
+    runner::{format_time, Outcome, PartResult},
+};
+
+#[derive(Clone, Copy, Default, Debug, PartialEq, ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    /// The usual progress lines and tables
+    #[default]
+    Text,
+    /// One JSON object per line
+    Json,
+    Csv,
+    Markdown,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+pub struct Record {
+    pub day: u8,
+    pub title: &'static str,
+    pub part: char,
+    /// only set when solved
+    pub answer: Option<String>,
+    pub status: &'static str,
+    /// `None` if the part never ran
+    pub time_ns: Option<u64>,
+}
+
+impl Record {
+    pub fn new(solution: &dyn DynSolution, part: char, result: &PartResult) -> Self {
+        let (answer, status) = match &result.outcome {
+            Outcome::Solved(answer) => (Some(answer.to_string()), "solved"),
+            Outcome::Unimplemented => (None, "unimplemented"),
+            Outcome::Failed(_) => (None, "failed"),
+            Outcome::Panicked(_) => (None, "panicked"),
+            Outcome::TimedOut(_) => (None, "timed out"),
+        };
+
+        let ran = !matches!(result.outcome, Outcome::Unimplemented) && !result.time.is_zero();
+
+        Self {
+            day: solution.day(),
+            title: solution.name(),
+            part,
+            answer,
+            status,
+            time_ns: ran.then_some(result.time.as_nanos() as u64),
+        }
+    }
+
+    /// a part that didn't get to run, e.g. `"no input"`
+    pub fn skipped(solution: &dyn DynSolution, part: char, status: &'static str) -> Self {
+        Self {
+            day: solution.day(),
+            title: solution.name(),
+            part,
+            answer: None,
+            status,
+            time_ns: None,
+        }
+    }
+}
+
+/// the records as `format`, `Text` has its own output and renders nothing
+pub fn render<'a>(format: Format, records: impl IntoIterator<Item = &'a Record>) -> String {
+    match format {
+        Format::Text => String::new(),
+        Format::Json => records
+            .into_iter()
+            .map(|r| serde_json::to_string(r).unwrap() + "\n")
+            .collect(),
+        Format::Csv => {
+            let mut out = "day,title,part,answer,status,time_ns\n".to_string();
+
+            for r in records {
+                let fields = [
+                    r.day.to_string(),
+                    csv_field(r.title),
+                    r.part.to_string(),
+                    csv_field(r.answer.as_deref().unwrap_or_default()),
+                    r.status.to_string(),
+                    r.time_ns.map(|t| t.to_string()).unwrap_or_default(),
+                ];
+
+                out += &fields.join(",");
+                out.push('\n');
+            }
+
+            out
+        }
+        Format::Markdown => {
+            let mut out = "| Day | Title | Part | Answer | Status | Time |\n".to_string();
+            out += "|----:|-------|:----:|--------|--------|-----:|\n";
+
+            for r in records {
+                let time = r.time_ns.map_or("-".into(), |t| format_time(t as f64));
+
+                out += &format!(
+                    "| {} | {} | {} | {} | {} | {time} |\n",
+                    r.day,
+                    markdown_cell(r.title),
+                    r.part.to_ascii_uppercase(),
+                    markdown_cell(r.answer.as_deref().unwrap_or("-")),
+                    r.status,
+                );
+            }
+
+            out
+        }
+    }
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.into()
+    }
+}
+
+pub fn markdown_cell(s: &str) -> String {
+    s.replace('|', "\\|").replace('\n', " ")
+}
+
+#[cfg(test)]
+mod test {
+    use super::{render, Format, Record};
+
+    use indoc::indoc;
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record {
+                day: 1,
+                title: "Trebuchet?!",
+                part: 'a',
+                answer: Some("142".into()),
+                status: "solved",
+                time_ns: Some(1500),
+            },
+            Record {
+                day: 2,
+                title: "Cube, \"Conundrum\"",
+                part: 'b',
+                answer: None,
+                status: "no input",
+                time_ns: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(
+            render(Format::Json, &records()),
+            indoc! {r#"
+                {"day":1,"title":"Trebuchet?!","part":"a","answer":"142","status":"solved","time_ns":1500}
+                {"day":2,"title":"Cube, \"Conundrum\"","part":"b","answer":null,"status":"no input","time_ns":null}
+            "#}
+        );
+    }
+
+    #[test]
+    fn csv() {
+        assert_eq!(
+            render(Format::Csv, &records()),
+            indoc! {r#"
+                day,title,part,answer,status,time_ns
+                1,Trebuchet?!,a,142,solved,1500
+                2,"Cube, ""Conundrum""",b,,no input,
+            "#}
+        );
+    }
+
+    #[test]
+    fn markdown() {
+        assert_eq!(
+            render(Format::Markdown, &records()),
+            indoc! {r#"
+                | Day | Title | Part | Answer | Status | Time |
+                |----:|-------|:----:|--------|--------|-----:|
+                | 1 | Trebuchet?! | A | 142 | solved | 1.500μs |
+                | 2 | Cube, "Conundrum" | B | - | no input | - |
+            "#}
+        );
+    }
+}
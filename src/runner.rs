@@ -0,0 +1,792 @@
+use std::{
+    cell::Cell,
+    collections::BTreeMap,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        mpsc::{self, Receiver, RecvTimeoutError},
+        Arc, Mutex, Once,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answers::AnswerStore,
+    common::{
+        cancel,
+        progress::{self, Progress},
+        Answer, DynSolution, ParsedInput, SolveError,
+    },
+    input::{self, CacheMode},
+    memory::{self, Usage},
+    report::{self, Format, Record},
+    solutions,
+};
+
+#[derive(Clone)]
+pub enum Outcome {
+    Solved(Answer),
+    Unimplemented,
+    Failed(SolveError),
+    Panicked(String),
+    TimedOut(Duration),
+}
+
+impl Outcome {
+    pub fn is_error(&self) -> bool {
+        matches!(
+            self,
+            Outcome::Failed(_) | Outcome::Panicked(_) | Outcome::TimedOut(_)
+        )
+    }
+
+    pub fn cell(&self) -> String {
+        match self {
+            Outcome::Solved(answer) => answer.to_string(),
+            Outcome::Unimplemented => "unimplemented".into(),
+            Outcome::Failed(e) => format!("ERROR: {}", e.message),
+            Outcome::Panicked(msg) => format!("PANIC: {msg}"),
+            Outcome::TimedOut(limit) => {
+                format!("TIMEOUT after {}", format_time(limit.as_nanos() as f64))
+            }
+        }
+    }
+}
+
+pub struct PartResult {
+    pub outcome: Outcome,
+    pub time: Duration,
+    /// `None` unless built with the counting allocator
+    pub memory: Option<Usage>,
+}
+
+impl PartResult {
+    fn time_cell(&self) -> String {
+        match self.outcome {
+            Outcome::Solved(_) => format_time(self.time.as_nanos() as f64),
+            _ => "-".into(),
+        }
+    }
+
+    fn memory_cell(&self) -> String {
+        match (&self.outcome, self.memory) {
+            (Outcome::Solved(_), Some(usage)) => usage.to_string(),
+            _ => "-".into(),
+        }
+    }
+
+    /// the answer, its time, then its memory use if counted
+    fn cells(&self) -> Vec<String> {
+        let mut cells = vec![self.outcome.cell(), self.time_cell()];
+        if memory::ENABLED {
+            cells.push(self.memory_cell());
+        }
+
+        cells
+    }
+}
+
+pub struct DayResult {
+    /// `None` if parsing failed, every part left then has the parse step's outcome
+    pub parse_time: Option<Duration>,
+    pub parts: Vec<PartResult>,
+}
+
+impl DayResult {
+    fn parse_time_cell(&self) -> String {
+        match self.parse_time {
+            Some(time) => format_time(time.as_nanos() as f64),
+            None => "-".into(),
+        }
+    }
+}
+
+enum Event {
+    Parsed(Result<Duration, Outcome>),
+    Part(PartResult),
+}
+
+#[derive(Clone, Copy)]
+pub struct RunOptions {
+    /// how long a single step may take
+    pub limit: Option<Duration>,
+    /// draw the progress solutions report on stderr
+    pub progress: bool,
+}
+
+/// the limit for a timeout in seconds, `None` for 0. negative, NaN and values
+/// too large for a `Duration` are errors rather than no limit
+pub fn time_limit(seconds: f64) -> Result<Option<Duration>, String> {
+    if seconds == 0.0 {
+        return Ok(None);
+    }
+
+    if seconds.is_nan() || seconds < 0.0 {
+        return Err(format!("timeout must be 0 or more seconds, got {seconds}"));
+    }
+
+    Duration::try_from_secs_f64(seconds)
+        .map(Some)
+        .map_err(|_| format!("timeout of {seconds} seconds is too large"))
+}
+
+/// parses the input once and runs the given parts on it, on a worker thread.
+/// a step that takes longer than `limit` is given up on and the day goes on
+/// with a fresh worker, the old one keeps running until it checks
+/// `cancel::check` or the process exits
+pub fn run_day(
+    solution: &'static dyn DynSolution,
+    day: u8,
+    parts: &[char],
+    input: &str,
+    options: RunOptions,
+) -> DayResult {
+    let limit = options.limit;
+    let input = Arc::<str>::from(input);
+    let mut result = DayResult {
+        parse_time: None,
+        parts: Vec::new(),
+    };
+
+    while result.parts.len() < parts.len() {
+        let remaining = &parts[result.parts.len()..];
+        let flag = Arc::new(AtomicBool::new(false));
+        let progress = Arc::new(Progress::default());
+        let events = spawn_worker(
+            solution,
+            day,
+            remaining,
+            input.clone(),
+            flag.clone(),
+            progress.clone(),
+        );
+
+        let progress = options.progress.then_some(progress.as_ref());
+        let next = |label: String| wait(&events, limit, progress.map(|p| (p, label)));
+
+        let failed = match next(format!("day {day} (parsing)")) {
+            Ok(Event::Parsed(Ok(time))) => {
+                result.parse_time.get_or_insert(time);
+                None
+            }
+            Ok(Event::Parsed(Err(outcome))) => Some(outcome),
+            Ok(Event::Part(_)) => unreachable!("parts come after parsing"),
+            Err(e) => Some(gave_up(e, limit, &flag)),
+        };
+
+        if let Some(outcome) = failed {
+            for _ in remaining {
+                result.parts.push(PartResult {
+                    outcome: outcome.clone(),
+                    time: Duration::ZERO,
+                    memory: None,
+                });
+            }
+
+            break;
+        }
+
+        for part in remaining {
+            match next(format!("day {day} ({})", part.to_ascii_uppercase())) {
+                Ok(Event::Part(part)) => result.parts.push(part),
+                Ok(Event::Parsed(_)) => unreachable!("parsing happens once per worker"),
+                Err(e) => {
+                    result.parts.push(PartResult {
+                        outcome: gave_up(e, limit, &flag),
+                        time: limit.unwrap_or_default(),
+                        memory: None,
+                    });
+
+                    break;
+                }
+            }
+        }
+    }
+
+    result
+}
+
+fn spawn_worker(
+    solution: &'static dyn DynSolution,
+    day: u8,
+    parts: &[char],
+    input: Arc<str>,
+    flag: Arc<AtomicBool>,
+    progress: Arc<Progress>,
+) -> Receiver<Event> {
+    let (tx, rx) = mpsc::channel();
+    let parts = parts.to_vec();
+
+    thread::spawn(move || {
+        silence_panics();
+        cancel::install(flag);
+        progress::install(progress.clone());
+
+        let start = Instant::now();
+        let parsed = panic::catch_unwind(AssertUnwindSafe(|| solution.parse(&input)));
+        let time = start.elapsed();
+
+        // a send only fails once the runner stopped waiting for this worker
+        let parsed = match parsed {
+            Ok(Ok(parsed)) => parsed,
+            Ok(Err(e)) => {
+                let _ = tx.send(Event::Parsed(Err(Outcome::Failed(e.parsing(day)))));
+                return;
+            }
+            Err(payload) => {
+                let msg = panic_message(payload.as_ref());
+                let _ = tx.send(Event::Parsed(Err(Outcome::Panicked(msg))));
+                return;
+            }
+        };
+
+        if tx.send(Event::Parsed(Ok(time))).is_err() {
+            return;
+        }
+
+        for part in parts {
+            progress.reset();
+
+            if tx
+                .send(Event::Part(run_part(parsed.as_ref(), day, part)))
+                .is_err()
+            {
+                return;
+            }
+        }
+    });
+
+    rx
+}
+
+thread_local! {
+    static SILENT: Cell<bool> = const { Cell::new(false) };
+}
+
+/// keeps the panic hook quiet on this thread, its panics are caught and
+/// reported as outcomes. other threads still get the previous hook
+fn silence_panics() {
+    static HOOK: Once = Once::new();
+
+    HOOK.call_once(|| {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !SILENT.get() {
+                hook(info);
+            }
+        }));
+    });
+
+    SILENT.set(true);
+}
+
+/// waits for the worker's next event, drawing its progress meanwhile
+fn wait(
+    events: &Receiver<Event>,
+    limit: Option<Duration>,
+    progress: Option<(&Progress, String)>,
+) -> Result<Event, RecvTimeoutError> {
+    const REFRESH: Duration = Duration::from_millis(100);
+
+    let start = Instant::now();
+    let mut drawn = false;
+
+    let event = loop {
+        let left = limit.map_or(REFRESH, |limit| limit.saturating_sub(start.elapsed()));
+
+        match events.recv_timeout(left.min(REFRESH)) {
+            Err(RecvTimeoutError::Timeout) if left > REFRESH || limit.is_none() => {}
+            event => break event,
+        }
+
+        let Some((label, (done, total))) = progress.as_ref().and_then(|(p, l)| Some((l, p.get()?)))
+        else {
+            continue;
+        };
+
+        let elapsed = start.elapsed().as_secs_f64();
+        let eta = match done {
+            0 => "?".into(),
+            _ => format_time(elapsed * (total - done) as f64 / done as f64 * 1e9),
+        };
+
+        eprint!(
+            "\r\x1b[2K[~] {label}: {:.1}% ({done}/{total}), ETA {eta}",
+            done as f64 / total as f64 * 100.0
+        );
+        drawn = true;
+    };
+
+    if drawn {
+        eprint!("\r\x1b[2K");
+    }
+
+    event
+}
+
+fn gave_up(e: RecvTimeoutError, limit: Option<Duration>, flag: &AtomicBool) -> Outcome {
+    flag.store(true, Ordering::Relaxed);
+
+    match e {
+        RecvTimeoutError::Timeout => Outcome::TimedOut(limit.unwrap_or_default()),
+        RecvTimeoutError::Disconnected => Outcome::Panicked("worker thread stopped".into()),
+    }
+}
+
+pub fn run_part(parsed: &dyn ParsedInput, day: u8, part: char) -> PartResult {
+    let start = Instant::now();
+    let (out, memory) =
+        memory::measure(|| panic::catch_unwind(AssertUnwindSafe(|| parsed.solve(part))));
+    let time = start.elapsed();
+
+    let outcome = match out {
+        Ok(Ok(Answer::Unimplemented)) => Outcome::Unimplemented,
+        Ok(Ok(answer)) => Outcome::Solved(answer),
+        Ok(Err(e)) => Outcome::Failed(e.context(day, part)),
+        Err(payload) => Outcome::Panicked(panic_message(payload.as_ref())),
+    };
+
+    PartResult {
+        outcome,
+        time,
+        memory,
+    }
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".into()
+    }
+}
+
+struct Row {
+    day: u8,
+    name: &'static str,
+    /// everything after the name, one per `headers()` column
+    cells: Vec<String>,
+    records: Vec<Record>,
+    time: Duration,
+    ok: bool,
+    error: Option<String>,
+}
+
+impl Row {
+    /// a day that didn't get to run its parts, `message` goes in the part A column
+    fn failed(
+        solution: &dyn DynSolution,
+        message: String,
+        status: &'static str,
+        error: Option<String>,
+    ) -> Self {
+        let mut cells = vec!["-".to_string(); headers().len() - 2];
+        cells[1] = message;
+
+        Self {
+            day: solution.day(),
+            name: solution.name(),
+            cells,
+            records: ['a', 'b']
+                .map(|part| Record::skipped(solution, part, status))
+                .into(),
+            time: Duration::ZERO,
+            ok: false,
+            error,
+        }
+    }
+}
+
+fn headers() -> Vec<&'static str> {
+    let mut headers = vec!["Day", "Name", "Parse"];
+
+    for part in [
+        ["Part A", "Time A", "Memory A"],
+        ["Part B", "Time B", "Memory B"],
+    ] {
+        let columns = if memory::ENABLED { 3 } else { 2 };
+        headers.extend(&part[..columns]);
+    }
+
+    headers
+}
+
+/// returns whether every implemented part ran without failing
+pub fn run_all(
+    year: u16,
+    mode: CacheMode,
+    options: RunOptions,
+    jobs: usize,
+    format: Format,
+) -> bool {
+    let solutions = match solutions::year(year) {
+        Ok(solutions) => solutions,
+        Err(e) => {
+            eprintln!("[-] {e}");
+            return false;
+        }
+    };
+
+    let rows = run_rows(year, &solutions, mode, options, jobs);
+
+    if format == Format::Text {
+        print_table(&rows);
+
+        let total = rows.iter().map(|r| r.time).sum::<Duration>();
+        println!("[*] Total: {}", format_time(total.as_nanos() as f64));
+    } else {
+        let records = rows.iter().flat_map(|r| &r.records);
+        print!("{}", report::render(format, records));
+    }
+
+    for e in rows.iter().filter_map(|r| r.error.as_ref()) {
+        eprintln!("[-] {e}");
+    }
+
+    rows.iter().all(|r| r.ok)
+}
+
+/// both parts of every registered day, in day order. days without an input are
+/// reported and left as `"no input"` records
+pub fn run_records(
+    year: u16,
+    mode: CacheMode,
+    options: RunOptions,
+    jobs: usize,
+) -> Result<Vec<Record>, String> {
+    let solutions = solutions::year(year)?;
+    let rows = run_rows(year, &solutions, mode, options, jobs);
+
+    for e in rows.iter().filter_map(|r| r.error.as_ref()) {
+        eprintln!("[-] {e}");
+    }
+
+    Ok(rows.into_iter().flat_map(|r| r.records).collect())
+}
+
+fn run_rows(
+    year: u16,
+    solutions: &[&'static dyn DynSolution],
+    mode: CacheMode,
+    options: RunOptions,
+    jobs: usize,
+) -> Vec<Row> {
+    // days running side by side would draw over each other
+    let options = RunOptions {
+        progress: options.progress && jobs == 1,
+        ..options
+    };
+
+    let rows = parallel(solutions, jobs, |solution| {
+        panic::catch_unwind(AssertUnwindSafe(|| run_row(year, *solution, mode, options)))
+            .unwrap_or_else(|payload| {
+                let message = format!("PANIC: {}", panic_message(payload.as_ref()));
+                Row::failed(*solution, message, "panicked", None)
+            })
+    });
+
+    rows
+}
+
+fn run_row(
+    year: u16,
+    solution: &'static dyn DynSolution,
+    mode: CacheMode,
+    options: RunOptions,
+) -> Row {
+    let day = solution.day();
+
+    let input = match input::load(year, day, mode) {
+        Ok(input) => input,
+        Err(e) => return Row::failed(solution, "no input".into(), "no input", Some(e.to_string())),
+    };
+
+    let result = run_day(solution, day, &['a', 'b'], &input, options);
+    let [a, b] = &result.parts[..] else {
+        unreachable!()
+    };
+
+    let mut cells = vec![result.parse_time_cell()];
+    cells.extend(a.cells());
+    cells.extend(b.cells());
+
+    Row {
+        day,
+        name: solution.name(),
+        cells,
+        records: vec![Record::new(solution, 'a', a), Record::new(solution, 'b', b)],
+        time: result.parse_time.unwrap_or_default() + a.time + b.time,
+        ok: !a.outcome.is_error() && !b.outcome.is_error(),
+        error: None,
+    }
+}
+
+/// runs every part with a stored answer, returns whether all of them matched
+pub fn verify(year: u16, mode: CacheMode, options: RunOptions, jobs: usize) -> bool {
+    let store = match AnswerStore::load(year) {
+        Ok(store) => store,
+        Err(e) => {
+            eprintln!("[-] {e}");
+            return false;
+        }
+    };
+
+    let mut days = BTreeMap::<u8, Vec<(char, &str)>>::new();
+    for (day, part, expected) in store.iter() {
+        days.entry(day).or_default().push((part, expected));
+    }
+    let days = days.into_iter().collect::<Vec<_>>();
+
+    let options = RunOptions {
+        progress: options.progress && jobs == 1,
+        ..options
+    };
+
+    let checks = parallel(&days, jobs, |(day, answers)| {
+        panic::catch_unwind(AssertUnwindSafe(|| {
+            verify_day(year, *day, answers, mode, options)
+        }))
+        .unwrap_or_else(|payload| {
+            let msg = panic_message(payload.as_ref());
+            answers
+                .iter()
+                .map(|(part, _)| Err(format!("Day {day} ({}): PANIC: {msg}", part.to_uppercase())))
+                .collect()
+        })
+    });
+
+    let mut checked = 0;
+    let mut failed = 0;
+
+    for check in checks.into_iter().flatten() {
+        checked += 1;
+
+        match check {
+            Ok(line) => println!("[+] {line}"),
+            Err(line) => {
+                println!("[-] {line}");
+                failed += 1;
+            }
+        }
+    }
+
+    println!("[*] {}/{checked} answers match", checked - failed);
+
+    failed == 0
+}
+
+/// one line per stored answer, `Err` if it did not match
+fn verify_day(
+    year: u16,
+    day: u8,
+    answers: &[(char, &str)],
+    mode: CacheMode,
+    options: RunOptions,
+) -> Vec<Result<String, String>> {
+    let labels = answers
+        .iter()
+        .map(|(part, _)| format!("Day {day} ({})", part.to_uppercase()));
+
+    let input = solutions::get(year, day).and_then(|solution| {
+        let input = input::load(year, day, mode).map_err(|e| e.to_string())?;
+        Ok((solution, input))
+    });
+
+    let (solution, input) = match input {
+        Ok(found) => found,
+        Err(e) => return labels.map(|label| Err(format!("{label}: {e}"))).collect(),
+    };
+
+    let parts = answers.iter().map(|(part, _)| *part).collect::<Vec<_>>();
+    let result = run_day(solution, day, &parts, &input, options);
+
+    labels
+        .zip(answers)
+        .zip(result.parts)
+        .map(|((label, (_, expected)), result)| match result.outcome {
+            Outcome::Solved(answer) if answer.to_string() == *expected => {
+                Ok(format!("{label}: {answer}"))
+            }
+            outcome => Err(format!(
+                "{label}: expected {expected}, got {}",
+                outcome.cell()
+            )),
+        })
+        .collect()
+}
+
+/// calls `f` on every item from `jobs` threads, the results keep the items' order
+fn parallel<T: Sync, R: Send>(items: &[T], jobs: usize, f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(Vec::with_capacity(items.len()));
+
+    thread::scope(|s| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            s.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(i) else {
+                    break;
+                };
+
+                let result = f(item);
+                results.lock().unwrap().push((i, result));
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|(i, _)| *i);
+    results.into_iter().map(|(_, r)| r).collect()
+}
+
+fn print_table(rows: &[Row]) {
+    let headers = headers();
+    let mut widths = headers.iter().map(|h| h.len()).collect::<Vec<_>>();
+
+    for row in rows {
+        widths[0] = widths[0].max(row.day.to_string().len());
+        widths[1] = widths[1].max(row.name.chars().count());
+        for (w, cell) in widths[2..].iter_mut().zip(&row.cells) {
+            *w = (*w).max(cell.chars().count());
+        }
+    }
+
+    let line = |cells: Vec<&str>| {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(c, w)| format!("{c:<w$}"))
+            .collect::<Vec<_>>()
+            .join(" | ")
+    };
+
+    println!("{}", line(headers.clone()));
+    println!(
+        "{}",
+        widths
+            .iter()
+            .map(|w| "-".repeat(*w))
+            .collect::<Vec<_>>()
+            .join("-+-")
+    );
+
+    for row in rows {
+        let day = row.day.to_string();
+        let mut cells = vec![day.as_str(), row.name];
+        cells.extend(row.cells.iter().map(String::as_str));
+        println!("{}", line(cells));
+    }
+}
+
+pub fn format_time(nanos: f64) -> String {
+    const TIME_UNITS: &[&str] = &["μs", "ms", "s"];
+
+    if nanos < 1000.0 {
+        return format!("{nanos:.0}ns");
+    }
+
+    let mut time = nanos;
+    for i in TIME_UNITS {
+        time /= 1000.0;
+        if time < 1000.0 {
+            return format!("{time:.3}{i}");
+        }
+    }
+
+    format!("{time:.3}{}", TIME_UNITS.last().unwrap())
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        sync::atomic::{AtomicBool, Ordering},
+        thread,
+        time::Duration,
+    };
+
+    use super::{parallel, run_day, time_limit, Outcome, RunOptions};
+    use crate::common::{cancel, Answer, Solution, SolveError};
+
+    static STOPPED: AtomicBool = AtomicBool::new(false);
+
+    struct Slow;
+
+    impl Solution for Slow {
+        type Parsed = ();
+
+        fn day(&self) -> u8 {
+            1
+        }
+
+        fn name(&self) -> &'static str {
+            "Slow"
+        }
+
+        fn parse(&self, _input: &str) -> Result<Self::Parsed, SolveError> {
+            Ok(())
+        }
+
+        fn part_a(&self, _: &Self::Parsed) -> Result<Answer, SolveError> {
+            loop {
+                if let Err(e) = cancel::check() {
+                    STOPPED.store(true, Ordering::Relaxed);
+                    return Err(e);
+                }
+
+                thread::sleep(Duration::from_millis(1));
+            }
+        }
+
+        fn part_b(&self, _: &Self::Parsed) -> Result<Answer, SolveError> {
+            Ok(2.into())
+        }
+    }
+
+    #[test]
+    fn limits() {
+        assert_eq!(time_limit(0.0), Ok(None));
+        assert_eq!(time_limit(1.5), Ok(Some(Duration::from_millis(1500))));
+        assert!(time_limit(-1.0).is_err());
+        assert!(time_limit(f64::NAN).is_err());
+        assert!(time_limit(f64::INFINITY).is_err());
+        assert!(time_limit(1e30).is_err());
+    }
+
+    #[test]
+    fn parallel_order() {
+        let items = (0..50).collect::<Vec<u64>>();
+        let results = parallel(&items, 4, |i| {
+            thread::sleep(Duration::from_micros(50 - i));
+            i * 2
+        });
+
+        assert_eq!(results, items.iter().map(|i| i * 2).collect::<Vec<_>>());
+        assert!(parallel(&[] as &[u8], 4, |i| *i).is_empty());
+    }
+
+    #[test]
+    fn timeout() {
+        let options = RunOptions {
+            limit: Some(Duration::from_millis(50)),
+            progress: false,
+        };
+        let result = run_day(&Slow, 1, &['a', 'b'], "", options);
+
+        assert!(result.parse_time.is_some());
+        assert!(matches!(result.parts[0].outcome, Outcome::TimedOut(_)));
+        assert!(matches!(&result.parts[1].outcome, Outcome::Solved(a) if *a == 2.into()));
+
+        for _ in 0..100 {
+            if STOPPED.load(Ordering::Relaxed) {
+                return;
+            }
+
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        panic!("the worker did not stop");
+    }
+}
@@ -0,0 +1,216 @@
+use std::{fs, path::PathBuf};
+
+use crate::{client::Client, html, solutions};
+
+const SOLUTIONS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/solutions");
+
+/// writes `day_NN.rs` from the template and registers it, returns its path
+pub fn new_day(
+    year: u16,
+    day: u8,
+    name: Option<String>,
+) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("there is no day {day}, puzzles run from 1 to 25").into());
+    }
+
+    // registering a whole new event is left to a human
+    solutions::year(year)?;
+
+    let dir = PathBuf::from(SOLUTIONS_DIR).join(format!("y{year}"));
+    let path = dir.join(format!("day_{day:02}.rs"));
+
+    if path.exists() {
+        return Err(format!("{} already exists", path.display()).into());
+    }
+
+    let name = match name {
+        Some(name) => name,
+        None => fetch_title(year, day)?,
+    };
+
+    let mod_path = dir.join("mod.rs");
+    let registry = register(&fs::read_to_string(&mod_path)?, day)?;
+
+    fs::write(&path, template(day, &name))?;
+    fs::write(&mod_path, registry)?;
+
+    Ok(path)
+}
+
+fn fetch_title(year: u16, day: u8) -> Result<String, Box<dyn std::error::Error>> {
+    let client =
+        Client::from_config().ok_or("no session token is set, pass the title with --name")?;
+    let page = client.puzzle(year, day)?;
+
+    puzzle_title(&page).ok_or_else(|| "could not find the puzzle title, pass it with --name".into())
+}
+
+// <h2>--- Day 1: Trebuchet?! ---</h2>
+pub fn puzzle_title(page: &str) -> Option<String> {
+    let start = page.find("<h2>")?;
+    let end = start + page[start..].find("</h2>")?;
+
+    let heading = html::strip_tags(&page[start..end]);
+    let (_, title) = heading.trim().trim_matches('-').split_once(':')?;
+
+    Some(title.trim().to_string())
+}
+
+fn template(day: u8, name: &str) -> String {
+    format!(
+        r#"use crate::common::{{Answer, Solution, SolveError}};
+
+pub struct Day{day:02};
+
+impl Solution for Day{day:02} {{
+    type Parsed = Vec<String>;
+
+    fn day(&self) -> u8 {{
+        {day}
+    }}
+
+    fn name(&self) -> &'static str {{
+        {name:?}
+    }}
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError> {{
+        Ok(input.lines().map(String::from).collect())
+    }}
+
+    fn part_a(&self, _lines: &Self::Parsed) -> Result<Answer, SolveError> {{
+        Ok(Answer::Unimplemented)
+    }}
+
+    fn part_b(&self, _lines: &Self::Parsed) -> Result<Answer, SolveError> {{
+        Ok(Answer::Unimplemented)
+    }}
+}}
+
+#[cfg(test)]
+mod test {{
+    use super::Day{day:02};
+    use crate::common::{{Answer, DynSolution}};
+
+    use indoc::indoc;
+
+    const INPUT: &str = indoc! {{"
+    "}};
+
+    #[test]
+    fn part_a() {{
+        assert_eq!(Day{day:02}.solve(INPUT, 'a'), Ok(Answer::Unimplemented));
+    }}
+
+    #[test]
+    fn part_b() {{
+        assert_eq!(Day{day:02}.solve(INPUT, 'b'), Ok(Answer::Unimplemented));
+    }}
+}}
+"#
+    )
+}
+
+/// adds the `mod` declaration and the `SOLUTIONS` entry to `solutions/yYYYY/mod.rs`
+fn register(registry: &str, day: u8) -> Result<String, String> {
+    let mut lines = registry.lines().map(String::from).collect::<Vec<_>>();
+
+    let module = format!("mod day_{day:02};");
+    if lines.contains(&module) {
+        return Err(format!("day {day} is already registered"));
+    }
+
+    insert_sorted(&mut lines, "mod day_", module)?;
+    insert_sorted(
+        &mut lines,
+        "    &day_",
+        format!("    &day_{day:02}::Day{day:02},"),
+    )?;
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// keeps the days in order, they're zero padded so sorting them as strings works
+fn insert_sorted(lines: &mut Vec<String>, prefix: &str, line: String) -> Result<(), String> {
+    let last = lines
+        .iter()
+        .rposition(|l| l.starts_with(prefix))
+        .ok_or_else(|| format!("could not find `{prefix}` in the year's mod.rs"))?;
+
+    let index = lines
+        .iter()
+        .position(|l| l.starts_with(prefix) && *l > line)
+        .unwrap_or(last + 1);
+
+    lines.insert(index, line);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{new_day, puzzle_title, register};
+
+    use indoc::indoc;
+
+    #[test]
+    fn title() {
+        let page = r#"<main><article class="day-desc"><h2>--- Day 7: Camel &amp; Cards ---</h2><p>Your all-expenses-paid trip"#;
+
+        assert_eq!(puzzle_title(page), Some("Camel & Cards".into()));
+    }
+
+    #[test]
+    fn out_of_range() {
+        // rejected before anything is written
+        assert!(new_day(2023, 0, Some("Nothing".into())).is_err());
+        assert!(new_day(2023, 26, Some("Nothing".into())).is_err());
+    }
+
+    #[test]
+    fn registry() {
+        let registry = indoc! {"
+            mod day_01;
+            mod day_02;
+
+            pub const SOLUTIONS: &[&dyn Solution] = &[
+                &day_01::Day01,
+                &day_02::Day02,
+            ];
+        "};
+
+        assert_eq!(
+            register(registry, 3).unwrap(),
+            indoc! {"
+                mod day_01;
+                mod day_02;
+                mod day_03;
+
+                pub const SOLUTIONS: &[&dyn Solution] = &[
+                    &day_01::Day01,
+                    &day_02::Day02,
+                    &day_03::Day03,
+                ];
+            "}
+        );
+
+        assert_eq!(
+            register(registry, 5).and_then(|r| register(&r, 4)).unwrap(),
+            indoc! {"
+                mod day_01;
+                mod day_02;
+                mod day_04;
+                mod day_05;
+
+                pub const SOLUTIONS: &[&dyn Solution] = &[
+                    &day_01::Day01,
+                    &day_02::Day02,
+                    &day_04::Day04,
+                    &day_05::Day05,
+                ];
+            "}
+        );
+
+        assert!(register(registry, 2).is_err());
+    }
+}
@@ -1,31 +1,86 @@
-use crate::common::Solution;
-
-mod day_01;
-mod day_02;
-mod day_03;
-mod day_04;
-mod day_05;
-mod day_06;
-mod day_07;
-mod day_08;
-mod day_09;
-mod day_10;
-mod day_11;
-mod day_12;
-mod day_13;
-
-pub const SOLUTIONS: &[&dyn Solution] = &[
-    &day_01::Day01,
-    &day_02::Day02,
-    &day_03::Day03,
-    &day_04::Day04,
-    &day_05::Day05,
-    &day_06::Day06,
-    &day_07::Day07,
-    &day_08::Day08,
-    &day_09::Day09,
-    &day_10::Day10,
-    &day_11::Day11,
-    &day_12::Day12,
-    &day_13::Day13,
-];
+use crate::common::DynSolution;
+
+mod y2023;
+
+/// every registered event, in any order, each solution declares its own day.
+/// solutions are `Sync` so `all` and `verify` can run days side by side
+pub const YEARS: &[(u16, &[&dyn DynSolution])] = &[(2023, y2023::SOLUTIONS)];
+
+/// makes sure every day is a valid puzzle day and is registered only once,
+/// ran at startup since `Solution::day` can't be checked at compile time
+pub fn check() -> Result<(), String> {
+    for (i, (year, solutions)) in YEARS.iter().enumerate() {
+        if YEARS[..i].iter().any(|(y, _)| y == year) {
+            return Err(format!("{year} is registered twice"));
+        }
+
+        for (j, solution) in solutions.iter().enumerate() {
+            let day = solution.day();
+
+            if !(1..=25).contains(&day) {
+                return Err(format!(
+                    "{year} registers {}, day {day} is not a puzzle day",
+                    solution.name()
+                ));
+            }
+
+            if let Some(other) = solutions[..j].iter().find(|s| s.day() == day) {
+                return Err(format!(
+                    "{year} registers day {day} twice: {} and {}",
+                    other.name(),
+                    solution.name()
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// the year's solutions sorted by day
+pub fn year(year: u16) -> Result<Vec<&'static dyn DynSolution>, String> {
+    let (_, solutions) = YEARS
+        .iter()
+        .find(|(y, _)| *y == year)
+        .ok_or_else(|| format!("No solutions registered for {year}"))?;
+
+    let mut solutions = solutions.to_vec();
+    solutions.sort_by_key(|s| s.day());
+
+    Ok(solutions)
+}
+
+pub fn get(year: u16, day: u8) -> Result<&'static dyn DynSolution, String> {
+    let solutions = self::year(year)?;
+
+    if let Some(solution) = solutions.iter().find(|s| s.day() == day) {
+        return Ok(*solution);
+    }
+
+    let days = solutions
+        .iter()
+        .map(|s| s.day().to_string())
+        .collect::<Vec<_>>();
+
+    Err(format!(
+        "Day {day} not implemented for {year}, registered days: {}",
+        days.join(", ")
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{check, get};
+
+    #[test]
+    fn registry() {
+        assert_eq!(check(), Ok(()));
+    }
+
+    #[test]
+    fn missing_days() {
+        assert!(get(2023, 0).is_err());
+        assert!(get(2023, 26).is_err());
+        assert!(get(2022, 1).is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::common::{Answer, Solution};
+use crate::common::{Answer, Solution, SolveError};
 
 pub struct Day01;
 
@@ -7,14 +7,24 @@ const NUMBERS: &[&str] = &[
 ];
 
 impl Solution for Day01 {
+    type Parsed = Vec<String>;
+
+    fn day(&self) -> u8 {
+        1
+    }
+
     fn name(&self) -> &'static str {
         "Trebuchet?!"
     }
 
-    fn part_a(&self, input: &str) -> Answer {
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part_a(&self, lines: &Self::Parsed) -> Result<Answer, SolveError> {
         let mut sum = 0;
 
-        for line in input.lines() {
+        for line in lines {
             let mut numbers = line.chars().filter(|c| c.is_numeric());
 
             let Some(first) = numbers.next() else {
@@ -31,13 +41,13 @@ impl Solution for Day01 {
             }
         }
 
-        sum.into()
+        Ok(sum.into())
     }
 
-    fn part_b(&self, input: &str) -> Answer {
+    fn part_b(&self, lines: &Self::Parsed) -> Result<Answer, SolveError> {
         let mut sum = 0;
 
-        for line in input.lines() {
+        for line in lines {
             let mut numbers = line
                 .chars()
                 .enumerate()
@@ -62,7 +72,7 @@ impl Solution for Day01 {
             }
         }
 
-        sum.into()
+        Ok(sum.into())
     }
 }
 
@@ -95,7 +105,7 @@ fn find_words(input: &str) -> Vec<(usize, char)> {
 #[cfg(test)]
 mod test {
     use super::Day01;
-    use crate::common::Solution;
+    use crate::common::DynSolution;
 
     use indoc::indoc;
 
@@ -118,11 +128,11 @@ mod test {
 
     #[test]
     fn part_a() {
-        assert_eq!(Day01.part_a(INPUT_A), 142.into());
+        assert_eq!(Day01.solve(INPUT_A, 'a'), Ok(142.into()));
     }
 
     #[test]
     fn part_b() {
-        assert_eq!(Day01.part_b(INPUT_B), 281.into());
+        assert_eq!(Day01.solve(INPUT_B, 'b'), Ok(281.into()));
     }
 }
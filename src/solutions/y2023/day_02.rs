@@ -1,32 +1,38 @@
-use crate::common::{Answer, Solution};
+use crate::common::{Answer, Solution, SolveError};
 
 pub struct Day02;
 
 impl Solution for Day02 {
+    type Parsed = Vec<Game>;
+
+    fn day(&self) -> u8 {
+        2
+    }
+
     fn name(&self) -> &'static str {
         "Cube Conundrum"
     }
 
-    fn part_a(&self, input: &str) -> Answer {
-        let mut sum = 0;
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError> {
+        input.lines().map(parse_game).collect()
+    }
 
-        for (i, line) in input.lines().enumerate() {
-            let game = parse_game(line);
+    fn part_a(&self, games: &Self::Parsed) -> Result<Answer, SolveError> {
+        let mut sum = 0;
 
+        for (i, game) in games.iter().enumerate() {
             if game.iter().all(|s| s.0 <= 12 && s.1 <= 13 && s.2 <= 14) {
                 sum += i + 1;
             }
         }
 
-        sum.into()
+        Ok(sum.into())
     }
 
-    fn part_b(&self, input: &str) -> Answer {
+    fn part_b(&self, games: &Self::Parsed) -> Result<Answer, SolveError> {
         let mut sum = 0;
 
-        for line in input.lines() {
-            let game = parse_game(line);
-
+        for game in games {
             let r = game.iter().map(|(r, _, _)| r).max().unwrap();
             let g = game.iter().map(|(_, g, _)| g).max().unwrap();
             let b = game.iter().map(|(_, _, b)| b).max().unwrap();
@@ -34,22 +40,27 @@ impl Solution for Day02 {
             sum += r * g * b;
         }
 
-        sum.into()
+        Ok(sum.into())
     }
 }
 
-fn parse_game(line: &str) -> Vec<(u32, u32, u32)> {
+type Game = Vec<(u32, u32, u32)>;
+
+fn parse_game(line: &str) -> Result<Game, SolveError> {
     let mut game = Vec::new();
 
-    let sets = line.split_once(':').unwrap().1.split(';');
-    for set in sets {
+    let (_, sets) = line
+        .split_once(':')
+        .ok_or_else(|| SolveError::new(format!("missing ':' in game: {line}")))?;
+
+    for set in sets.split(';') {
         let mut cols = (0, 0, 0);
 
         for color in set.split(',') {
             let color = color.trim();
 
             if let Some((n, color)) = color.split_once(' ') {
-                let n = n.parse::<u32>().unwrap();
+                let n = n.parse::<u32>()?;
                 match color {
                     "red" => cols.0 = n,
                     "green" => cols.1 = n,
@@ -62,13 +73,13 @@ fn parse_game(line: &str) -> Vec<(u32, u32, u32)> {
         game.push(cols);
     }
 
-    game
+    Ok(game)
 }
 
 #[cfg(test)]
 mod test {
     use super::Day02;
-    use crate::common::Solution;
+    use crate::common::DynSolution;
 
     use indoc::indoc;
 
@@ -82,11 +93,11 @@ mod test {
 
     #[test]
     fn part_a() {
-        assert_eq!(Day02.part_a(INPUT), 8.into());
+        assert_eq!(Day02.solve(INPUT, 'a'), Ok(8.into()));
     }
 
     #[test]
     fn part_b() {
-        assert_eq!(Day02.part_b(INPUT), 2286.into());
+        assert_eq!(Day02.solve(INPUT, 'b'), Ok(2286.into()));
     }
 }
@@ -1,22 +1,37 @@
 use std::collections::HashMap;
 
-use crate::common::{Answer, Solution};
+use crate::common::{Answer, Solution, SolveError};
 
 pub struct Day03;
 
 impl Solution for Day03 {
+    type Parsed = Schematic;
+
+    fn day(&self) -> u8 {
+        3
+    }
+
     fn name(&self) -> &'static str {
         "Gear Ratios"
     }
 
-    fn part_a(&self, input: &str) -> Answer {
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError> {
+        Ok(Schematic {
+            lines: input.lines().map(String::from).collect(),
+            numbers: get_numbers(input),
+            gears: get_gears(input),
+        })
+    }
+
+    fn part_a(&self, schematic: &Self::Parsed) -> Result<Answer, SolveError> {
         let mut sum = 0;
 
-        for ((line_i, c_i), num) in get_numbers(input) {
+        for (&(line_i, c_i), &num) in &schematic.numbers {
             let len = num.to_string().len();
 
-            let lines = input
-                .lines()
+            let lines = schematic
+                .lines
+                .iter()
                 .skip(line_i.saturating_sub(1)) // avoid underflow
                 .take(if line_i == 0 { 2 } else { 3 });
 
@@ -27,14 +42,17 @@ impl Solution for Day03 {
             }
         }
 
-        sum.into()
+        Ok(sum.into())
     }
 
-    fn part_b(&self, input: &str) -> Answer {
-        let gears = get_gears(input);
-        let mut ratios = gears.iter().map(|c| (c, vec![])).collect::<HashMap<_, _>>();
+    fn part_b(&self, schematic: &Self::Parsed) -> Result<Answer, SolveError> {
+        let mut ratios = schematic
+            .gears
+            .iter()
+            .map(|c| (c, vec![]))
+            .collect::<HashMap<_, _>>();
 
-        for ((line_i, c_i), num) in get_numbers(input) {
+        for (&(line_i, c_i), &num) in &schematic.numbers {
             let len = num.to_string().len();
 
             for line in line_i.saturating_sub(1)..=(line_i + 1) {
@@ -46,15 +64,21 @@ impl Solution for Day03 {
             }
         }
 
-        ratios
+        Ok(ratios
             .values()
             .filter(|v| v.len() == 2)
             .map(|v| v[0] * v[1])
             .sum::<u32>()
-            .into()
+            .into())
     }
 }
 
+pub struct Schematic {
+    lines: Vec<String>,
+    numbers: HashMap<(usize, usize), u32>,
+    gears: Vec<(usize, usize)>,
+}
+
 fn get_numbers(input: &str) -> HashMap<(usize, usize), u32> {
     let mut numbers = HashMap::new();
 
@@ -93,7 +117,7 @@ fn get_gears(input: &str) -> Vec<(usize, usize)> {
 #[cfg(test)]
 mod test {
     use super::Day03;
-    use crate::common::Solution;
+    use crate::common::DynSolution;
 
     use indoc::indoc;
 
@@ -112,11 +136,11 @@ mod test {
 
     #[test]
     fn part_a() {
-        assert_eq!(Day03.part_a(INPUT), 4361.into());
+        assert_eq!(Day03.solve(INPUT, 'a'), Ok(4361.into()));
     }
 
     #[test]
     fn part_b() {
-        assert_eq!(Day03.part_b(INPUT), 467835.into());
+        assert_eq!(Day03.solve(INPUT, 'b'), Ok(467835.into()));
     }
 }
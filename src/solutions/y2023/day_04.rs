@@ -1,16 +1,26 @@
-use crate::common::{Answer, Solution};
+use crate::common::{Answer, Solution, SolveError};
 
 pub struct Day04;
 
 impl Solution for Day04 {
+    type Parsed = Vec<Card>;
+
+    fn day(&self) -> u8 {
+        4
+    }
+
     fn name(&self) -> &'static str {
         "Scratchcards"
     }
 
-    fn part_a(&self, input: &str) -> Answer {
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError> {
+        input.lines().map(parse_card).collect()
+    }
+
+    fn part_a(&self, cards: &Self::Parsed) -> Result<Answer, SolveError> {
         let mut sum = 0;
 
-        for card in parse_cards(input) {
+        for card in cards {
             let winning = card
                 .winning
                 .iter()
@@ -22,11 +32,10 @@ impl Solution for Day04 {
             }
         }
 
-        sum.into()
+        Ok(sum.into())
     }
 
-    fn part_b(&self, input: &str) -> Answer {
-        let cards = parse_cards(input);
+    fn part_b(&self, cards: &Self::Parsed) -> Result<Answer, SolveError> {
         let mut amounts = vec![1; cards.len()];
 
         for (i, card) in cards.iter().enumerate() {
@@ -45,39 +54,38 @@ impl Solution for Day04 {
                 .for_each(|a| *a += current_amount);
         }
 
-        amounts.iter().sum::<u32>().into()
+        Ok(amounts.iter().sum::<u32>().into())
     }
 }
 
-struct Card {
+pub struct Card {
     winning: Vec<u32>,
     numbers: Vec<u32>,
 }
 
-fn parse_cards(input: &str) -> Vec<Card> {
-    input.lines().map(parse_card).collect()
-}
-
-fn parse_card(line: &str) -> Card {
-    let (winning, numbers) = line.split_once(':').unwrap().1.split_once('|').unwrap();
+fn parse_card(line: &str) -> Result<Card, SolveError> {
+    let (winning, numbers) = line
+        .split_once(':')
+        .and_then(|(_, card)| card.split_once('|'))
+        .ok_or_else(|| SolveError::new(format!("malformed card: {line}")))?;
 
     let winning = winning
         .split_whitespace()
-        .filter_map(|s| s.parse::<u32>().ok())
-        .collect::<Vec<_>>();
+        .map(|s| s.parse::<u32>())
+        .collect::<Result<Vec<_>, _>>()?;
 
     let numbers = numbers
         .split_whitespace()
-        .filter_map(|s| s.parse::<u32>().ok())
-        .collect::<Vec<_>>();
+        .map(|s| s.parse::<u32>())
+        .collect::<Result<Vec<_>, _>>()?;
 
-    Card { winning, numbers }
+    Ok(Card { winning, numbers })
 }
 
 #[cfg(test)]
 mod test {
     use super::Day04;
-    use crate::common::Solution;
+    use crate::common::DynSolution;
 
     use indoc::indoc;
 
@@ -92,11 +100,11 @@ mod test {
 
     #[test]
     fn part_a() {
-        assert_eq!(Day04.part_a(INPUT), 13.into());
+        assert_eq!(Day04.solve(INPUT, 'a'), Ok(13.into()));
     }
 
     #[test]
     fn part_b() {
-        assert_eq!(Day04.part_b(INPUT), 30.into());
+        assert_eq!(Day04.solve(INPUT, 'b'), Ok(30.into()));
     }
 }
@@ -1,40 +1,63 @@
 use std::{ops::Range, str::FromStr};
 
-use crate::common::{Answer, Solution};
+use crate::common::{cancel, progress, Answer, Solution, SolveError};
 
 pub struct Day05;
 
 impl Solution for Day05 {
+    type Parsed = Almanac;
+
+    fn day(&self) -> u8 {
+        5
+    }
+
     fn name(&self) -> &'static str {
         "If You Give A Seed A Fertilizer"
     }
 
-    fn part_a(&self, input: &str) -> Answer {
-        let almanac = input.parse::<Almanac>().unwrap();
-        let min = almanac.seeds.iter().map(|s| almanac.map(*s)).min().unwrap();
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError> {
+        input.parse()
+    }
 
-        min.into()
+    fn part_a(&self, almanac: &Self::Parsed) -> Result<Answer, SolveError> {
+        let min = almanac
+            .seeds
+            .iter()
+            .map(|s| almanac.map(*s))
+            .min()
+            .ok_or_else(|| SolveError::new("no seeds"))?;
+
+        Ok(min.into())
     }
 
     // yes this takes 93 seconds to run.
-    fn part_b(&self, input: &str) -> Answer {
-        let almanac = input.parse::<Almanac>().unwrap();
+    fn part_b(&self, almanac: &Self::Parsed) -> Result<Answer, SolveError> {
         let mut min = u32::MAX;
 
-        for range in almanac.seed_ranges() {
+        let ranges = almanac.seed_ranges();
+        let total = ranges.iter().map(|r| r.len() as u64).sum();
+        let mut done = 0;
+
+        for range in ranges {
             for seed in range {
+                done += 1;
+                if done % 1_000_000 == 0 {
+                    cancel::check()?;
+                    progress::report(done, total);
+                }
+
                 let seed = almanac.map(seed);
                 min = min.min(seed);
             }
         }
 
-        min.into()
+        Ok(min.into())
     }
 }
 
 // ===
 
-struct Almanac {
+pub struct Almanac {
     seeds: Vec<u32>,
     maps: Vec<Map>,
 }
@@ -59,12 +82,15 @@ impl Almanac {
 }
 
 impl FromStr for Almanac {
-    type Err = std::num::ParseIntError;
+    type Err = SolveError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut lines = s.lines();
 
-        let (_, seeds) = lines.next().unwrap().split_once(':').unwrap();
+        let (_, seeds) = lines
+            .next()
+            .and_then(|l| l.split_once(':'))
+            .ok_or_else(|| SolveError::new("missing seeds line"))?;
         let seeds: Vec<u32> = seeds
             .split_whitespace()
             .map(|n| n.parse())
@@ -107,7 +133,7 @@ impl Map {
 }
 
 impl FromStr for Map {
-    type Err = std::num::ParseIntError;
+    type Err = SolveError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let ranges = s
@@ -134,15 +160,18 @@ impl MapRange {
 }
 
 impl FromStr for MapRange {
-    type Err = std::num::ParseIntError;
+    type Err = SolveError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let numbers = s.split_whitespace().take(3).collect::<Vec<_>>();
+        let [dest_start, source_start, len] = numbers[..] else {
+            return Err(SolveError::new(format!("expected 3 numbers in range: {s}")));
+        };
 
         Ok(Self {
-            dest_start: numbers[0].parse()?,
-            source_start: numbers[1].parse()?,
-            len: numbers[2].parse()?,
+            dest_start: dest_start.parse()?,
+            source_start: source_start.parse()?,
+            len: len.parse()?,
         })
     }
 }
@@ -152,7 +181,7 @@ impl FromStr for MapRange {
 #[cfg(test)]
 mod test {
     use super::Day05;
-    use crate::common::Solution;
+    use crate::common::DynSolution;
 
     use indoc::indoc;
 
@@ -194,11 +223,11 @@ mod test {
 
     #[test]
     fn part_a() {
-        assert_eq!(Day05.part_a(INPUT), 35.into());
+        assert_eq!(Day05.solve(INPUT, 'a'), Ok(35.into()));
     }
 
     #[test]
     fn part_b() {
-        assert_eq!(Day05.part_b(INPUT), 46.into());
+        assert_eq!(Day05.solve(INPUT, 'b'), Ok(46.into()));
     }
 }
@@ -0,0 +1,158 @@
+use crate::common::{Answer, ParseError, Solution, SolveError};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Parsed = Sheet;
+
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn name(&self) -> &'static str {
+        "Wait For It"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError> {
+        let mut lines = input.lines();
+
+        Ok(Sheet {
+            times: digits(input, lines.next())?,
+            distances: digits(input, lines.next())?,
+        })
+    }
+
+    fn part_a(&self, sheet: &Self::Parsed) -> Result<Answer, SolveError> {
+        races(sheet)?
+            .iter()
+            .try_fold(1u64, |product, r| {
+                product
+                    .checked_mul(r.winning_times()?)
+                    .ok_or_else(|| SolveError::new("too many ways to win"))
+            })
+            .map(Into::into)
+    }
+
+    fn part_b(&self, sheet: &Self::Parsed) -> Result<Answer, SolveError> {
+        let race = Race {
+            time: sheet.times.concat().parse()?,
+            best_distance: sheet.distances.concat().parse()?,
+        };
+
+        Ok(race.winning_times()?.into())
+    }
+}
+
+/// the numbers as written, part a reads them one by one and part b with the
+/// spaces removed, so each part does its own overflow checks
+pub struct Sheet {
+    times: Vec<String>,
+    distances: Vec<String>,
+}
+
+fn digits(input: &str, line: Option<&str>) -> Result<Vec<String>, SolveError> {
+    let numbers = line
+        .and_then(|l| l.split_once(':'))
+        .map(|(_, numbers)| numbers)
+        .ok_or_else(|| SolveError::new("expected a `Name: numbers` line"))?;
+
+    numbers
+        .split_whitespace()
+        .map(|n| {
+            if !n.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(ParseError::at(input, n, "expected a number").into());
+            }
+
+            Ok(n.to_string())
+        })
+        .collect()
+}
+
+fn races(sheet: &Sheet) -> Result<Vec<Race>, SolveError> {
+    sheet
+        .times
+        .iter()
+        .zip(&sheet.distances)
+        .map(|(time, dist)| {
+            Ok(Race {
+                time: time.parse()?,
+                best_distance: dist.parse()?,
+            })
+        })
+        .collect()
+}
+
+pub struct Race {
+    time: u64,
+    best_distance: u64,
+}
+
+impl Race {
+    fn winning_times(&self) -> Result<u64, SolveError> {
+        let too_long = || SolveError::new("race is too long to time");
+
+        // +1 because you're not beating the distance if you tie it
+        let square = self.time.checked_pow(2).ok_or_else(too_long)?;
+        let to_beat = self
+            .best_distance
+            .checked_add(1)
+            .and_then(|d| d.checked_mul(4))
+            .ok_or_else(too_long)?;
+
+        // no way to go far enough
+        let Some(delta) = square.checked_sub(to_beat) else {
+            return Ok(0);
+        };
+        let sq_delta = (delta as f64).sqrt();
+
+        let t1 = (self.time as f64 - sq_delta) / 2.0;
+        let t1 = t1.ceil() as u64;
+
+        let t2 = (self.time as f64 + sq_delta) / 2.0;
+        let t2 = t2.floor() as u64;
+
+        Ok(t2 + 1 - t1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Day06;
+    use crate::common::DynSolution;
+
+    use indoc::indoc;
+
+    const INPUT: &str = indoc! {"
+        Time:      7  15   30
+        Distance:  9  40  200
+    "};
+
+    #[test]
+    fn part_a() {
+        assert_eq!(Day06.solve(INPUT, 'a'), Ok(288.into()));
+    }
+
+    #[test]
+    fn part_b() {
+        assert_eq!(Day06.solve(INPUT, 'b'), Ok(71503.into()));
+    }
+
+    #[test]
+    fn parts_apart() {
+        let input = indoc! {"
+            Time:      7  15   30        100000000
+            Distance:  9  40  200 2499999999999999
+        "};
+
+        assert_eq!(Day06.solve(input, 'a'), Ok(288.into()));
+        assert!(Day06.solve(input, 'b').is_err());
+    }
+
+    #[test]
+    fn unwinnable() {
+        let input = "Time: 1\nDistance: 5\n";
+
+        assert_eq!(Day06.solve(input, 'a'), Ok(0.into()));
+        assert_eq!(Day06.solve(input, 'b'), Ok(0.into()));
+    }
+}
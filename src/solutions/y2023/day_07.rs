@@ -1,35 +1,57 @@
+use std::ops::Range;
+
 use itertools::Itertools;
 
-use crate::common::{Answer, Solution};
+use crate::common::{Answer, ParseError, Solution, SolveError};
 
 pub struct Day07;
 
 impl Solution for Day07 {
+    type Parsed = Vec<(Hand, u32)>;
+
+    fn day(&self) -> u8 {
+        7
+    }
+
     fn name(&self) -> &'static str {
         "Camel Cards"
     }
 
-    fn part_a(&self, input: &str) -> Answer {
-        compute_winnings_sum(input, false).into()
-    }
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError> {
+        let mut hands = Vec::new();
 
-    fn part_b(&self, input: &str) -> Answer {
-        compute_winnings_sum(input, true).into()
-    }
-}
+        for line in input.lines() {
+            let (hand, bid) = line
+                .split_once(' ')
+                .ok_or_else(|| ParseError::at(input, line, "expected `<hand> <bid>`"))?;
 
-fn compute_winnings_sum(input: &str, with_joker: bool) -> u32 {
-    let mut hands = Vec::new();
+            let hand = Hand::from_str(hand)
+                .map_err(|(cards, message)| ParseError::at(input, &hand[cards], message))?;
+            let bid = bid
+                .parse::<u32>()
+                .map_err(|e| ParseError::at(input, bid, format!("invalid bid: {e}")))?;
 
-    for line in input.lines() {
-        let (hand, bid) = line.split_once(' ').unwrap();
+            hands.push((hand, bid));
+        }
 
-        let hand = Hand::from_str(hand, with_joker).unwrap();
-        let bid = bid.parse::<u32>().unwrap();
+        Ok(hands)
+    }
 
-        hands.push((hand, bid));
+    fn part_a(&self, hands: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(compute_winnings_sum(hands.clone()).into())
     }
 
+    fn part_b(&self, hands: &Self::Parsed) -> Result<Answer, SolveError> {
+        let hands = hands
+            .iter()
+            .map(|(h, bid)| (h.with_jokers(), *bid))
+            .collect();
+
+        Ok(compute_winnings_sum(hands).into())
+    }
+}
+
+fn compute_winnings_sum(mut hands: Vec<(Hand, u32)>) -> u32 {
     hands.sort_by_key(|(h, _)| *h);
     let mut winnings = 0;
 
@@ -41,25 +63,37 @@ fn compute_winnings_sum(input: &str, with_joker: bool) -> u32 {
 }
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
-struct Hand {
+pub struct Hand {
     hand_type: HandType,
     cards: [Card; 5],
 }
 
 impl Hand {
-    fn from_str(s: &str, with_joker: bool) -> Result<Self, Vec<Card>> {
+    /// on error, the bytes of `s` at fault and why
+    fn from_str(s: &str) -> Result<Self, (Range<usize>, String)> {
         let cards: [Card; 5] = s
-            .chars()
-            .take(5)
-            .map(|c| Card::from_char(c, with_joker))
-            .collect::<Vec<_>>()
-            .try_into()?;
+            .char_indices()
+            .map(|(i, c)| Card::from_char(c).map_err(|e| (i..i + c.len_utf8(), e.message)))
+            .collect::<Result<Vec<_>, _>>()?
+            .try_into()
+            .map_err(|_| (0..s.len(), format!("hand {s} does not have 5 cards")))?;
 
         Ok(Self {
             hand_type: HandType::from_cards(&cards),
             cards,
         })
     }
+
+    fn with_jokers(self) -> Self {
+        let cards = self
+            .cards
+            .map(|c| if c == Card::Jack { Card::Joker } else { c });
+
+        Self {
+            hand_type: HandType::from_cards(&cards),
+            cards,
+        }
+    }
 }
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
@@ -81,18 +115,12 @@ enum Card {
 }
 
 impl Card {
-    fn from_char(value: char, joker: bool) -> Self {
-        match value {
+    fn from_char(value: char) -> Result<Self, SolveError> {
+        let card = match value {
             'A' => Self::Ace,
             'K' => Self::King,
             'Q' => Self::Queen,
-            'J' => {
-                if joker {
-                    Self::Joker
-                } else {
-                    Self::Jack
-                }
-            }
+            'J' => Self::Jack,
             'T' => Self::Ten,
             '9' => Self::Nine,
             '8' => Self::Eight,
@@ -102,8 +130,10 @@ impl Card {
             '4' => Self::Four,
             '3' => Self::Three,
             '2' => Self::Two,
-            _ => unreachable!("card {value} doesnt exist"),
-        }
+            _ => return Err(SolveError::new(format!("card {value} doesnt exist"))),
+        };
+
+        Ok(card)
     }
 }
 
@@ -144,7 +174,7 @@ impl HandType {
 #[cfg(test)]
 mod test {
     use super::Day07;
-    use crate::common::Solution;
+    use crate::common::{DynSolution, ParseError, Solution};
 
     use indoc::indoc;
 
@@ -158,12 +188,35 @@ mod test {
 
     #[test]
     fn part_a() {
-        assert_eq!(Day07.part_a(INPUT), 6440.into());
+        assert_eq!(Day07.solve(INPUT, 'a'), Ok(6440.into()));
     }
 
     #[test]
     fn part_b() {
-        assert_eq!(Day07.part_b(INPUT), 5905.into());
+        assert_eq!(Day07.solve(INPUT, 'b'), Ok(5905.into()));
+    }
+
+    #[test]
+    fn bad_card() {
+        let span = |input| {
+            <Day07 as Solution>::parse(&Day07, input)
+                .err()
+                .unwrap()
+                .span
+        };
+
+        assert_eq!(
+            span("32T3K 765\nT5XJ5 684\n"),
+            Some(ParseError::new(1, 2..3, "card X doesnt exist"))
+        );
+        assert_eq!(
+            span("32T3K2 765\n"),
+            Some(ParseError::new(
+                0,
+                0..6,
+                "hand 32T3K2 does not have 5 cards"
+            ))
+        );
     }
 
     #[test]
@@ -2,24 +2,30 @@ use std::collections::HashMap;
 
 use gcd::Gcd;
 
-use crate::common::{Answer, Solution};
+use crate::common::{cancel, Answer, ParseError, Solution, SolveError};
 
 pub struct Day08;
 
 impl Solution for Day08 {
+    type Parsed = Map;
+
+    fn day(&self) -> u8 {
+        8
+    }
+
     fn name(&self) -> &'static str {
         "Haunted Wasteland"
     }
 
-    fn part_a(&self, input: &str) -> Answer {
-        let map = Map::parse(input);
-
-        map.compute_steps("AAA", |p| p == "ZZZ").into()
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError> {
+        Map::parse(input)
     }
 
-    fn part_b(&self, input: &str) -> Answer {
-        let map = Map::parse(input);
+    fn part_a(&self, map: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(map.compute_steps("AAA", |p| p == "ZZZ")?.into())
+    }
 
+    fn part_b(&self, map: &Self::Parsed) -> Result<Answer, SolveError> {
         let positions = map
             .nodes
             .keys()
@@ -29,40 +35,54 @@ impl Solution for Day08 {
         let steps = positions
             .iter()
             .map(|p| map.compute_steps(p, |end| end.ends_with('Z')))
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>, _>>()?;
 
         let steps = steps.iter().fold(1u64, |acc, next| lcm(acc, *next as u64));
 
-        steps.into()
+        Ok(steps.into())
     }
 }
 
-struct Map {
+pub struct Map {
     directions: Vec<Direction>,
     nodes: HashMap<String, (String, String)>,
 }
 
 impl Map {
-    fn parse(input: &str) -> Self {
+    fn parse(input: &str) -> Result<Self, SolveError> {
         let mut lines = input.lines();
 
         let directions = lines
             .next()
-            .unwrap()
+            .ok_or_else(|| SolveError::new("missing directions"))?;
+
+        let directions = directions
             .chars()
-            .filter_map(|c| match c {
-                'L' => Some(Direction::Left),
-                'R' => Some(Direction::Right),
-                _ => None,
+            .enumerate()
+            .map(|(i, c)| match c {
+                'L' => Ok(Direction::Left),
+                'R' => Ok(Direction::Right),
+                _ => Err(ParseError::new(
+                    0,
+                    i..i + 1,
+                    format!("unknown direction {c}"),
+                )),
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if directions.is_empty() {
+            return Err(SolveError::new("no directions"));
+        }
 
         lines.next(); // empty line
 
         let mut nodes = HashMap::new();
         for line in lines {
-            let (pos, paths) = line.split_once('=').unwrap();
-            let (left, right) = paths.trim().split_once(',').unwrap();
+            let (pos, paths) = line
+                .split_once('=')
+                .and_then(|(pos, paths)| Some((pos, paths.trim().split_once(',')?)))
+                .ok_or_else(|| ParseError::at(input, line, "expected `AAA = (BBB, CCC)`"))?;
+            let (left, right) = paths;
 
             let left = left.trim().chars().skip(1).collect::<String>();
             let right = right
@@ -74,16 +94,25 @@ impl Map {
             nodes.insert(pos.trim().into(), (left, right));
         }
 
-        Self { directions, nodes }
+        Ok(Self { directions, nodes })
     }
 
-    fn compute_steps(&self, start: impl ToString, break_cond: fn(&String) -> bool) -> u32 {
+    fn compute_steps(
+        &self,
+        start: impl ToString,
+        break_cond: fn(&String) -> bool,
+    ) -> Result<u32, SolveError> {
         let mut pos = start.to_string();
         let mut steps = 0u32;
 
         'l: loop {
+            cancel::check()?;
+
             for direction in &self.directions {
-                let paths = self.nodes.get(&pos).unwrap();
+                let paths = self
+                    .nodes
+                    .get(&pos)
+                    .ok_or_else(|| SolveError::new(format!("unknown node {pos}")))?;
                 pos = match direction {
                     Direction::Left => paths.0.clone(),
                     Direction::Right => paths.1.clone(),
@@ -97,7 +126,7 @@ impl Map {
             }
         }
 
-        steps
+        Ok(steps)
     }
 }
 
@@ -113,8 +142,8 @@ enum Direction {
 
 #[cfg(test)]
 mod test {
-    use super::Day08;
-    use crate::common::Solution;
+    use super::{Day08, Map};
+    use crate::common::{DynSolution, ParseError};
 
     use indoc::indoc;
 
@@ -145,11 +174,21 @@ mod test {
 
     #[test]
     fn part_a() {
-        assert_eq!(Day08.part_a(INPUT_A), 2.into());
+        assert_eq!(Day08.solve(INPUT_A, 'a'), Ok(2.into()));
     }
 
     #[test]
     fn part_b() {
-        assert_eq!(Day08.part_b(INPUT_B), 6.into());
+        assert_eq!(Day08.solve(INPUT_B, 'b'), Ok(6.into()));
+    }
+
+    #[test]
+    fn bad_direction() {
+        let error = Map::parse("LRé\n\nAAA = (AAA, AAA)\n").err().unwrap();
+
+        assert_eq!(
+            error.span,
+            Some(ParseError::new(0, 2..3, "unknown direction é"))
+        );
     }
 }
@@ -0,0 +1,92 @@
+use crate::common::{Answer, Solution, SolveError};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Parsed = Vec<Vec<i32>>;
+
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn name(&self) -> &'static str {
+        "Mirage Maintenance"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError> {
+        input.lines().map(parse_line).collect()
+    }
+
+    fn part_a(&self, histories: &Self::Parsed) -> Result<Answer, SolveError> {
+        let mut sum = 0;
+
+        for line in histories {
+            sum += get_next(line.clone());
+        }
+
+        Ok(sum.into())
+    }
+
+    fn part_b(&self, histories: &Self::Parsed) -> Result<Answer, SolveError> {
+        let mut sum = 0;
+
+        for line in histories {
+            let mut line = line.clone();
+            line.reverse();
+
+            sum += get_next(line);
+        }
+
+        Ok(sum.into())
+    }
+}
+
+fn parse_line(line: &str) -> Result<Vec<i32>, SolveError> {
+    let nums = line
+        .split_whitespace()
+        .map(|s| s.parse())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if nums.is_empty() {
+        return Err(SolveError::new("empty history"));
+    }
+
+    Ok(nums)
+}
+
+// who would've thought that learning recursive functions in scheme would help for me AoC
+fn get_next(nums: Vec<i32>) -> i32 {
+    let differences = nums.windows(2).map(|w| w[1] - w[0]).collect::<Vec<_>>();
+
+    let next = if differences.iter().all(|n| *n == 0) {
+        0
+    } else {
+        get_next(differences.clone())
+    };
+
+    next + nums.last().unwrap()
+}
+
+#[cfg(test)]
+mod test {
+    use super::Day09;
+    use crate::common::DynSolution;
+
+    use indoc::indoc;
+
+    const INPUT: &str = indoc! {"
+        0 3 6 9 12 15
+        1 3 6 10 15 21
+        10 13 16 21 30 45
+    "};
+
+    #[test]
+    fn part_a() {
+        assert_eq!(Day09.solve(INPUT, 'a'), Ok(114.into()));
+    }
+
+    #[test]
+    fn part_b() {
+        assert_eq!(Day09.solve(INPUT, 'b'), Ok(2.into()));
+    }
+}
@@ -1,75 +1,87 @@
-use crate::common::{Answer, Solution};
+use crate::common::{Answer, Solution, SolveError};
 
 pub struct Day10;
 
 impl Solution for Day10 {
+    type Parsed = Grid;
+
+    fn day(&self) -> u8 {
+        10
+    }
+
     fn name(&self) -> &'static str {
         "Pipe Maze"
     }
 
-    fn part_a(&self, input: &str) -> Answer {
-        let grid = parse_grid(input);
-        let mut tile = find_start(&grid);
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError> {
+        parse_grid(input)
+    }
+
+    fn part_a(&self, grid: &Self::Parsed) -> Result<Answer, SolveError> {
+        let mut tile = find_start(grid).ok_or_else(|| SolveError::new("no start tile"))?;
         let mut direction = Direction::all()
             .iter()
             .find(|d| {
                 let coords = tile.go(**d);
 
-                get_tile(&grid, coords)
+                get_tile(grid, coords)
                     .and_then(|t| t.get_direction(tile))
                     .is_some()
             })
             .copied()
-            .unwrap();
+            .ok_or_else(|| SolveError::new("no pipe connects to the start tile"))?;
 
         let mut distance = 0;
 
         loop {
             distance += 1;
 
-            let next_tile = get_tile(&grid, tile.go(direction)).unwrap();
+            let next_tile = get_tile(grid, tile.go(direction))
+                .ok_or_else(|| SolveError::new("loop leaves the grid"))?;
             if next_tile.tile_type == TileType::Start {
                 break;
             }
 
-            let next_direction = next_tile.get_direction(tile).unwrap();
+            let next_direction = next_tile
+                .get_direction(tile)
+                .ok_or_else(|| SolveError::new("loop is broken"))?;
 
             tile = next_tile;
             direction = next_direction;
         }
 
-        (distance / 2).into()
+        Ok((distance / 2).into())
     }
 
-    fn part_b(&self, _input: &str) -> Answer {
-        Answer::Unimplemented
+    fn part_b(&self, _grid: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(Answer::Unimplemented)
     }
 }
 
 type Grid = Vec<Vec<Tile>>;
 
-fn parse_grid(input: &str) -> Grid {
+fn parse_grid(input: &str) -> Result<Grid, SolveError> {
     input
         .lines()
         .enumerate()
         .map(|(line_idx, l)| {
             l.chars()
                 .enumerate()
-                .map(|(col_idx, c)| Tile {
-                    line_idx,
-                    col_idx,
-                    tile_type: c.into(),
+                .map(|(col_idx, c)| {
+                    Ok(Tile {
+                        line_idx,
+                        col_idx,
+                        tile_type: c.try_into()?,
+                    })
                 })
                 .collect()
         })
         .collect()
 }
 
-fn find_start(grid: &Grid) -> &Tile {
+fn find_start(grid: &Grid) -> Option<&Tile> {
     grid.iter()
-        .filter_map(|l| l.iter().find(|t| t.tile_type == TileType::Start))
-        .next()
-        .unwrap()
+        .find_map(|l| l.iter().find(|t| t.tile_type == TileType::Start))
 }
 
 fn get_tile(grid: &Grid, coords: (usize, usize)) -> Option<&Tile> {
@@ -77,7 +89,7 @@ fn get_tile(grid: &Grid, coords: (usize, usize)) -> Option<&Tile> {
 }
 
 #[derive(Clone, Copy, Debug)]
-struct Tile {
+pub struct Tile {
     line_idx: usize,
     col_idx: usize,
     tile_type: TileType,
@@ -86,10 +98,11 @@ struct Tile {
 impl Tile {
     fn go(&self, direction: Direction) -> (usize, usize) {
         match direction {
-            Direction::North => (self.line_idx - 1, self.col_idx),
+            // wrapping so that going off the grid is caught by get_tile
+            Direction::North => (self.line_idx.wrapping_sub(1), self.col_idx),
             Direction::South => (self.line_idx + 1, self.col_idx),
             Direction::East => (self.line_idx, self.col_idx + 1),
-            Direction::West => (self.line_idx, self.col_idx - 1),
+            Direction::West => (self.line_idx, self.col_idx.wrapping_sub(1)),
         }
     }
 
@@ -144,9 +157,11 @@ enum TileType {
     Start,
 }
 
-impl From<char> for TileType {
-    fn from(value: char) -> Self {
-        match value {
+impl TryFrom<char> for TileType {
+    type Error = SolveError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        let tile = match value {
             '|' => Self::VerticalPipe,
             '-' => Self::HorizontalPipe,
             'L' => Self::NorthEastPipe,
@@ -155,8 +170,10 @@ impl From<char> for TileType {
             'F' => Self::SouthEastPipe,
             '.' => Self::Ground,
             'S' => Self::Start,
-            _ => unreachable!("unknown tile: {value}"),
-        }
+            _ => return Err(SolveError::new(format!("unknown tile: {value}"))),
+        };
+
+        Ok(tile)
     }
 }
 
@@ -177,7 +194,7 @@ impl Direction {
 #[cfg(test)]
 mod test {
     use super::Day10;
-    use crate::common::Solution;
+    use crate::common::DynSolution;
 
     use indoc::indoc;
 
@@ -191,11 +208,14 @@ mod test {
 
     #[test]
     fn part_a() {
-        assert_eq!(Day10.part_a(INPUT), 8.into());
+        assert_eq!(Day10.solve(INPUT, 'a'), Ok(8.into()));
     }
 
     #[test]
     fn part_b() {
-        assert_eq!(Day10.part_b(INPUT), crate::common::Answer::Unimplemented);
+        assert_eq!(
+            Day10.solve(INPUT, 'b'),
+            Ok(crate::common::Answer::Unimplemented)
+        );
     }
 }
@@ -1,45 +1,64 @@
-use crate::common::{Answer, Solution};
+use crate::common::{Answer, Solution, SolveError};
 
 pub struct Day11;
 
 impl Solution for Day11 {
+    type Parsed = Universe;
+
+    fn day(&self) -> u8 {
+        11
+    }
+
     fn name(&self) -> &'static str {
         "Cosmic Expansion"
     }
 
-    fn part_a(&self, input: &str) -> Answer {
-        let galaxies = expanded_galaxies(input, 1);
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError> {
+        Ok(Universe {
+            galaxies: find_galaxies(input),
+            empty_lines: empty_lines(input),
+            empty_columns: empty_columns(input),
+        })
+    }
 
-        pairs(galaxies.len())
+    fn part_a(&self, universe: &Self::Parsed) -> Result<Answer, SolveError> {
+        let galaxies = universe.expanded_galaxies(1);
+
+        Ok(pairs(galaxies.len())
             .iter()
             .map(|(first, other)| distance(&galaxies, *first, *other))
             .sum::<usize>()
-            .into()
+            .into())
     }
 
-    fn part_b(&self, input: &str) -> Answer {
-        let galaxies = expanded_galaxies(input, 999_999);
+    fn part_b(&self, universe: &Self::Parsed) -> Result<Answer, SolveError> {
+        let galaxies = universe.expanded_galaxies(999_999);
 
-        pairs(galaxies.len())
+        Ok(pairs(galaxies.len())
             .iter()
             .map(|(first, other)| distance(&galaxies, *first, *other))
             .sum::<usize>()
-            .into()
+            .into())
     }
 }
 
-fn expanded_galaxies(input: &str, amount: usize) -> Vec<(usize, usize)> {
-    let empty_lines = empty_lines(input);
-    let empty_columns = empty_columns(input);
+pub struct Universe {
+    galaxies: Vec<(usize, usize)>,
+    empty_lines: Vec<usize>,
+    empty_columns: Vec<usize>,
+}
+
+impl Universe {
+    fn expanded_galaxies(&self, amount: usize) -> Vec<(usize, usize)> {
+        let mut galaxies = self.galaxies.clone();
 
-    let mut galaxies = find_galaxies(input);
+        for (line_i, col_i) in galaxies.iter_mut() {
+            *line_i += self.empty_lines.iter().filter(|i| *i < line_i).count() * amount;
+            *col_i += self.empty_columns.iter().filter(|i| *i < col_i).count() * amount;
+        }
 
-    for (line_i, col_i) in galaxies.iter_mut() {
-        *line_i += empty_lines.iter().filter(|i| *i < line_i).count() * amount;
-        *col_i += empty_columns.iter().filter(|i| *i < col_i).count() * amount;
+        galaxies
     }
-
-    galaxies
 }
 
 fn empty_lines(input: &str) -> Vec<usize> {
@@ -51,7 +70,7 @@ fn empty_lines(input: &str) -> Vec<usize> {
 }
 
 fn empty_columns(input: &str) -> Vec<usize> {
-    let len = input.lines().next().unwrap().len();
+    let len = input.lines().next().map_or(0, str::len);
 
     (0..len)
         .filter(|i| {
@@ -94,7 +113,7 @@ fn distance(galaxies: &[(usize, usize)], first: usize, other: usize) -> usize {
 #[cfg(test)]
 mod test {
     use super::Day11;
-    use crate::common::Solution;
+    use crate::common::DynSolution;
 
     use indoc::indoc;
 
@@ -113,11 +132,11 @@ mod test {
 
     #[test]
     fn part_a() {
-        assert_eq!(Day11.part_a(INPUT), 374.into());
+        assert_eq!(Day11.solve(INPUT, 'a'), Ok(374.into()));
     }
 
     #[test]
     fn part_b() {
-        assert_eq!(Day11.part_b(INPUT), 82000210.into());
+        assert_eq!(Day11.solve(INPUT, 'b'), Ok(82000210.into()));
     }
 }
@@ -0,0 +1,119 @@
+use crate::common::{progress, Answer, Solution, SolveError};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed = Vec<Row>;
+
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn name(&self) -> &'static str {
+        "Hot Springs"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError> {
+        input.lines().map(parse_row).collect()
+    }
+
+    fn part_a(&self, rows: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(rows
+            .iter()
+            .enumerate()
+            .map(|(i, (pattern, nums))| {
+                progress::report(i as u64, rows.len() as u64);
+                combinations(pattern, nums)
+            })
+            .sum::<u32>()
+            .into())
+    }
+
+    #[allow(unreachable_code, unused_variables)]
+    fn part_b(&self, rows: &Self::Parsed) -> Result<Answer, SolveError> {
+        return Ok(Answer::Unimplemented);
+
+        Ok(rows
+            .iter()
+            .map(|(p, n)| ([p.as_str()].repeat(5).join("?"), n.repeat(5)))
+            .enumerate()
+            .map(|(i, (pattern, nums))| {
+                progress::report(i as u64, rows.len() as u64);
+                combinations(&pattern, &nums)
+            })
+            .sum::<u32>()
+            .into())
+    }
+}
+
+type Row = (String, Vec<u32>);
+
+fn parse_row(line: &str) -> Result<Row, SolveError> {
+    let (pattern, nums) = line
+        .split_once(char::is_whitespace)
+        .ok_or_else(|| SolveError::new(format!("missing group sizes: {line}")))?;
+
+    let nums = nums
+        .split(',')
+        .map(|n| n.parse::<u32>())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((pattern.to_string(), nums))
+}
+
+fn combinations(pattern: &str, nums: &[u32]) -> u32 {
+    let mut patterns = vec![pattern.to_string()];
+
+    while patterns.first().unwrap().contains('?') {
+        let mut new_patterns = vec![];
+
+        for p in &patterns {
+            new_patterns.push(p.replacen('?', ".", 1));
+            new_patterns.push(p.replacen('?', "#", 1));
+        }
+
+        patterns = new_patterns;
+    }
+
+    patterns.iter().filter(|p| check(p, nums)).count() as u32
+}
+
+fn check(p: &str, nums: &[u32]) -> bool {
+    let lens = p
+        .split('.')
+        .filter(|s| !s.is_empty())
+        .map(|s| s.len() as u32)
+        .collect::<Vec<_>>();
+
+    lens == nums
+}
+
+#[cfg(test)]
+mod test {
+    use super::Day12;
+    use crate::common::DynSolution;
+
+    use indoc::indoc;
+
+    const INPUT: &str = indoc! {"
+        ???.### 1,1,3
+        .??..??...?##. 1,1,3
+        ?#?#?#?#?#?#?#? 1,3,1,6
+        ????.#...#... 4,1,1
+        ????.######..#####. 1,6,5
+        ?###???????? 3,2,1
+    "};
+
+    #[test]
+    fn part_a() {
+        assert_eq!(Day12.solve(INPUT, 'a'), Ok(21.into()));
+    }
+
+    #[test]
+    fn part_b() {
+        assert_eq!(
+            Day12.solve(INPUT, 'b'),
+            Ok(crate::common::Answer::Unimplemented)
+        );
+    }
+}
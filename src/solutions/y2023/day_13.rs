@@ -1,32 +1,61 @@
-use crate::common::{Answer, Solution};
+use crate::common::{Answer, ParseError, Solution, SolveError};
 
 pub struct Day13;
 
 impl Solution for Day13 {
+    type Parsed = Vec<Grid>;
+
+    fn day(&self) -> u8 {
+        13
+    }
+
     fn name(&self) -> &'static str {
         "Point of Incidence"
     }
 
-    fn part_a(&self, input: &str) -> Answer {
-        mirrors(input, 0).into()
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError> {
+        parse(input)
     }
 
-    fn part_b(&self, input: &str) -> Answer {
-        mirrors(input, 1).into()
+    fn part_a(&self, patterns: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(mirrors(patterns, 0).into())
+    }
+
+    fn part_b(&self, patterns: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(mirrors(patterns, 1).into())
     }
 }
 
 type Grid = Vec<Vec<char>>;
 
-fn parse(input: &str) -> Vec<Grid> {
+/// every pattern has to be a non-empty rectangle
+fn parse(input: &str) -> Result<Vec<Grid>, SolveError> {
     input
         .split("\n\n")
-        .map(|p| p.lines().map(|l| l.chars().collect()).collect())
+        .map(|pattern| {
+            let width = pattern.lines().next().map_or(0, |l| l.chars().count());
+            if width == 0 {
+                return Err(SolveError::new("empty pattern"));
+            }
+
+            pattern
+                .lines()
+                .map(|line| {
+                    let row = line.chars().collect::<Vec<_>>();
+                    if row.len() != width {
+                        let message = format!("expected {width} tiles, got {}", row.len());
+                        return Err(ParseError::at(input, line, message).into());
+                    }
+
+                    Ok(row)
+                })
+                .collect()
+        })
         .collect()
 }
 
-fn mirrors(input: &str, smudge: usize) -> usize {
-    parse(input)
+fn mirrors(patterns: &[Grid], smudge: usize) -> usize {
+    patterns
         .iter()
         .filter_map(|p| {
             horizontal_mirror(p, smudge)
@@ -95,7 +124,7 @@ fn vertical_mirror(input: &Grid, smudge: usize) -> Option<usize> {
 #[cfg(test)]
 mod test {
     use super::Day13;
-    use crate::common::Solution;
+    use crate::common::DynSolution;
 
     use indoc::indoc;
 
@@ -119,11 +148,17 @@ mod test {
 
     #[test]
     fn part_a() {
-        assert_eq!(Day13.part_a(INPUT), 405.into());
+        assert_eq!(Day13.solve(INPUT, 'a'), Ok(405.into()));
     }
 
     #[test]
     fn part_b() {
-        assert_eq!(Day13.part_b(INPUT), 400.into());
+        assert_eq!(Day13.solve(INPUT, 'b'), Ok(400.into()));
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(Day13.solve("", 'a').is_err());
+        assert!(Day13.solve("#.#\n##\n", 'a').is_err());
     }
 }
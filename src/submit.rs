@@ -0,0 +1,198 @@
+use std::{
+    fmt::{self, Display},
+    time::Duration,
+};
+
+use crate::{client::Client, html};
+
+#[derive(Debug, PartialEq)]
+pub enum Submission {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited(Option<Duration>),
+    AlreadySolved,
+    Unknown(String),
+}
+
+impl Display for Submission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Submission::Correct => write!(f, "That's the right answer!"),
+            Submission::TooHigh => write!(f, "Wrong answer, too high"),
+            Submission::TooLow => write!(f, "Wrong answer, too low"),
+            Submission::Wrong => write!(f, "Wrong answer"),
+            Submission::RateLimited(Some(wait)) => {
+                write!(f, "Answered too recently, wait {}s", wait.as_secs())
+            }
+            Submission::RateLimited(None) => write!(f, "Answered too recently"),
+            Submission::AlreadySolved => write!(f, "Already solved"),
+            Submission::Unknown(text) => write!(f, "Unknown reply: {text}"),
+        }
+    }
+}
+
+pub fn submit(
+    client: &Client,
+    year: u16,
+    day: u8,
+    part: char,
+    answer: &str,
+) -> Result<Submission, Box<dyn std::error::Error>> {
+    let level = if part == 'a' { 1 } else { 2 };
+    let html = client.answer(year, day, level, answer)?;
+
+    Ok(parse_reply(&html))
+}
+
+pub fn parse_reply(html: &str) -> Submission {
+    let text = article_text(html);
+
+    if text.contains("That's the right answer") {
+        Submission::Correct
+    } else if text.contains("That's not the right answer") {
+        if text.contains("too high") {
+            Submission::TooHigh
+        } else if text.contains("too low") {
+            Submission::TooLow
+        } else {
+            Submission::Wrong
+        }
+    } else if text.contains("You gave an answer too recently") {
+        Submission::RateLimited(parse_wait(&text))
+    } else if text.contains("You don't seem to be solving the right level") {
+        Submission::AlreadySolved
+    } else {
+        Submission::Unknown(text)
+    }
+}
+
+// the reply is a full page, only the <article> holds the message
+fn article_text(html: &str) -> String {
+    let article = html
+        .find("<article")
+        .and_then(|start| {
+            let end = html[start..].find("</article>")?;
+            Some(&html[start..start + end])
+        })
+        .unwrap_or(html);
+
+    html::strip_tags(article)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// "You have 1m 34s left to wait."
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+
+    let mut secs = 0;
+    for amount in text[start..end].split_whitespace() {
+        let (n, unit) = amount.split_at(amount.len() - 1);
+        let n = n.parse::<u64>().ok()?;
+
+        secs += match unit {
+            "h" => n * 3600,
+            "m" => n * 60,
+            "s" => n,
+            _ => return None,
+        };
+    }
+
+    Some(Duration::from_secs(secs))
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+        thread,
+        time::Duration,
+    };
+
+    use super::{parse_reply, submit, Submission};
+    use crate::client::Client;
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main><article><p>{message}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn replies() {
+        let cases = [
+            (
+                "That's the right answer! You are <span class=\"day-success\">one gold star</span> closer.",
+                Submission::Correct,
+            ),
+            (
+                "That's not the right answer; your answer is too high. Please wait one minute.",
+                Submission::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                Submission::TooLow,
+            ),
+            (
+                "That's not the right answer. If you're stuck, make sure you're using the full input data.",
+                Submission::Wrong,
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 34s left to wait.",
+                Submission::RateLimited(Some(Duration::from_secs(94))),
+            ),
+            (
+                "You don't seem to be solving the right level. Did you already complete it?",
+                Submission::AlreadySolved,
+            ),
+        ];
+
+        for (message, expected) in cases {
+            assert_eq!(parse_reply(&page(message)), expected);
+        }
+
+        assert_eq!(
+            parse_reply(&page("Something <em>else</em>")),
+            Submission::Unknown("Something else".into())
+        );
+    }
+
+    #[test]
+    fn local_server() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+            while !String::from_utf8_lossy(&request).contains("answer=") {
+                let n = stream.read(&mut buf).unwrap();
+                request.extend_from_slice(&buf[..n]);
+            }
+
+            let body = page("That's not the right answer; your answer is too low.");
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+
+            String::from_utf8(request).unwrap()
+        });
+
+        let client = Client::new(format!("http://{addr}/"), "abc");
+        let reply = submit(&client, 2023, 5, 'b', "42").unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(reply, Submission::TooLow);
+        assert!(request.starts_with("POST /2023/day/5/answer "));
+        assert!(request.contains("session=abc"));
+        assert!(request.contains("level=2&answer=42"));
+    }
+}